@@ -0,0 +1,74 @@
+use crate::fold::Graph;
+
+// Indices refer to the graph after the change, those past its end were removed
+#[derive(Default, Clone, Debug)]
+pub struct GraphChange {
+	pub frame_index: usize,
+	pub vertices: Vec<usize>,
+	pub edges: Vec<usize>,
+	pub faces: Vec<usize>,
+}
+
+#[derive(Clone, Debug)]
+pub enum Change {
+	Graph(GraphChange),
+	InsertedFrame(usize),
+	RemovedFrame(usize),
+}
+
+impl GraphChange {
+	pub fn new(frame_index: usize) -> Self {
+		return Self { frame_index, ..Default::default() }
+	}
+
+	pub fn is_empty(&self) -> bool {
+		return self.vertices.len() == 0 && self.edges.len() == 0 && self.faces.len() == 0;
+	}
+}
+
+fn changed_indices<T: PartialEq>(before: &[T], after: &[T]) -> Vec<usize> {
+	return (0..before.len().max(after.len())).filter(|&index| before.get(index) != after.get(index)).collect();
+}
+
+pub fn vertices_change(graph: &Graph, frame_index: usize, vertices: Vec<usize>) -> GraphChange {
+	let edges = (0..graph.edges_vertices.len())
+		.filter(|&edge_index| graph.edges_vertices[edge_index].iter().any(|vertex_index| vertices.contains(vertex_index)))
+		.collect();
+	let faces = (0..graph.faces_vertices.len())
+		.filter(|&face_index| graph.faces_vertices[face_index].iter().any(|vertex_index| vertices.contains(vertex_index)))
+		.collect();
+	return GraphChange { frame_index, vertices, edges, faces };
+}
+
+pub fn edges_change(graph: &Graph, frame_index: usize, edges: Vec<usize>) -> GraphChange {
+	let faces = (0..graph.faces_vertices.len()).filter(|&face_index| {
+		if let Some(face_edges) = graph.faces_edges.get(face_index) {
+			return face_edges.iter().any(|edge_index| edges.contains(edge_index));
+		}
+		let face_vertices = &graph.faces_vertices[face_index];
+		return edges.iter().filter_map(|&edge_index| graph.edges_vertices.get(edge_index)).any(|edge_vertices| {
+			edge_vertices.iter().all(|vertex_index| face_vertices.contains(vertex_index))
+		});
+	}).collect();
+	return GraphChange { frame_index, vertices: Vec::new(), edges, faces };
+}
+
+// Elements are compared index by index, along with everything incident to a changed vertex
+pub fn graphs_change(before: &Graph, after: &Graph, frame_index: usize) -> GraphChange {
+	let mut change = vertices_change(after, frame_index, changed_indices(&before.vertices_coordinates, &after.vertices_coordinates));
+
+	let mut edges = changed_indices(&before.edges_vertices, &after.edges_vertices);
+	edges.extend(changed_indices(&before.edges_assignment, &after.edges_assignment));
+	edges.extend(changed_indices(&before.edges_fold_angle, &after.edges_fold_angle));
+	edges.extend(change.edges.iter().copied());
+	edges.sort();
+	edges.dedup();
+	change.edges = edges;
+
+	let mut faces = changed_indices(&before.faces_vertices, &after.faces_vertices);
+	faces.extend(change.faces.iter().copied());
+	faces.sort();
+	faces.dedup();
+	change.faces = faces;
+	return change;
+}
\ No newline at end of file
@@ -0,0 +1,150 @@
+use crate::fold::{Fold, Frame, Graph, EdgeAssignment};
+use crate::geometry::Point;
+use crate::graph::arrangement::assignment_fold_angle;
+use crate::graph::spatial::SpatialIndex;
+use crate::graph::symmetry::{Symmetry, symmetric_edges, symmetric_edges_with_index, insert_symmetric_segment};
+use crate::real::Real;
+use super::change::{Change, vertices_change, edges_change, graphs_change};
+use super::error::EditError;
+use super::patch::{ElementsPatch, GraphPatch, ChildPatch};
+
+// Frame indices follow Fold::get_frame, commands edit the graph of the frame itself and not what it inherits,
+// creases and assignments are replicated to the images under the symmetries declared on the frame
+#[derive(Clone, Debug)]
+pub enum Command {
+	AddCrease(usize, Point, Point, EdgeAssignment),
+	SetAssignment(usize, usize, EdgeAssignment),
+	MoveVertex(usize, usize, Vec<Real>),
+	AddFrame(Box<Frame>),
+	RemoveFrame(usize),
+}
+
+// What restores the fold to its state before a command, graphs keep only the elements the command touched
+#[derive(Clone, Debug)]
+pub(crate) enum Inverse {
+	Graph(usize, Box<GraphPatch>),
+	Assignments(usize, Vec<usize>, ElementsPatch<EdgeAssignment>, ElementsPatch<Real>),
+	VertexCoordinates(usize, usize, Vec<Real>),
+	RemoveFrame(usize),
+	// The removed frame and the children it had, with their indices from before the removal
+	InsertFrame(usize, Box<Frame>, Vec<(usize, ChildPatch)>),
+}
+
+fn frame_graph(fold: &mut Fold, frame_index: usize) -> Result<&mut Graph, EditError> {
+	return fold.get_frame_mut(frame_index).map(|frame| &mut frame.graph).ok_or(EditError::InvalidFrame(frame_index));
+}
+
+fn frame_symmetries(fold: &Fold, frame_index: usize) -> Vec<Symmetry> {
+	return fold.get_frame(frame_index).map(|frame| frame.symmetries()).unwrap_or_default();
+}
+
+impl Command {
+	pub fn frame_index(&self) -> Option<usize> {
+		return match self {
+			Command::AddCrease(frame_index, ..) | Command::SetAssignment(frame_index, ..) | Command::MoveVertex(frame_index, ..) => Some(*frame_index),
+			Command::AddFrame(_) => None,
+			Command::RemoveFrame(frame_index) => Some(*frame_index),
+		};
+	}
+
+	// The spatial index, when given, is the one of the graph the command edits
+	pub(crate) fn apply(&self, fold: &mut Fold, index: Option<&SpatialIndex>) -> Result<(Change, Inverse), EditError> {
+		match self {
+			Command::AddCrease(frame_index, a, b, assignment) => {
+				let symmetries = frame_symmetries(fold, *frame_index);
+				let graph = frame_graph(fold, *frame_index)?;
+				let before = graph.clone();
+				insert_symmetric_segment(graph, &symmetries, a, b, *assignment);
+				let change = graphs_change(&before, graph, *frame_index);
+				return Ok((Change::Graph(change), Inverse::Graph(*frame_index, Box::new(GraphPatch::new(&before, graph)))));
+			},
+			Command::SetAssignment(frame_index, edge_index, assignment) => {
+				let symmetries = frame_symmetries(fold, *frame_index);
+				let graph = frame_graph(fold, *frame_index)?;
+				let edges_count = graph.edges_vertices.len();
+				if *edge_index >= edges_count { return Err(EditError::InvalidEdge(*edge_index)); }
+				let edges = match index {
+					Some(index) => symmetric_edges_with_index(graph, &symmetries, index, *edge_index),
+					None => symmetric_edges(graph, &symmetries, *edge_index),
+				};
+				let (edges_assignment, edges_fold_angle) = (graph.edges_assignment.clone(), graph.edges_fold_angle.clone());
+				graph.edges_assignment.resize(edges_count, EdgeAssignment::Unknown);
+				for &edge_index in edges.iter() {
+					graph.edges_assignment[edge_index] = *assignment;
+					if graph.edges_fold_angle.len() == edges_count {
+						graph.edges_fold_angle[edge_index] = assignment_fold_angle(*assignment);
+					}
+				}
+				let inverse = Inverse::Assignments(*frame_index, edges.clone(),
+					ElementsPatch::new(&edges_assignment, &graph.edges_assignment), ElementsPatch::new(&edges_fold_angle, &graph.edges_fold_angle));
+				return Ok((Change::Graph(edges_change(graph, *frame_index, edges)), inverse));
+			},
+			Command::MoveVertex(frame_index, vertex_index, coordinates) => {
+				let graph = frame_graph(fold, *frame_index)?;
+				let Some(vertex_coordinates) = graph.vertices_coordinates.get_mut(*vertex_index) else {
+					return Err(EditError::InvalidVertex(*vertex_index));
+				};
+				let before = std::mem::replace(vertex_coordinates, coordinates.clone());
+				let change = vertices_change(graph, *frame_index, vec![*vertex_index]);
+				return Ok((Change::Graph(change), Inverse::VertexCoordinates(*frame_index, *vertex_index, before)));
+			},
+			Command::AddFrame(frame) => {
+				fold.frames.push(*frame.clone());
+				let frame_index = fold.frames.len();
+				return Ok((Change::InsertedFrame(frame_index), Inverse::RemoveFrame(frame_index)));
+			},
+			Command::RemoveFrame(frame_index) => {
+				let children: Vec<(usize, ChildPatch)> = std::iter::once(&fold.key_frame).chain(fold.frames.iter()).enumerate()
+					.filter(|(_, frame)| frame.parent == Some(*frame_index))
+					.map(|(child_index, frame)| (child_index, ChildPatch::new(frame)))
+					.collect();
+				let frame = fold.remove_frame(*frame_index).ok_or(EditError::InvalidFrame(*frame_index))?;
+				return Ok((Change::RemovedFrame(*frame_index), Inverse::InsertFrame(*frame_index, Box::new(frame), children)));
+			},
+		}
+	}
+}
+
+impl Inverse {
+	pub(crate) fn apply(self, fold: &mut Fold) -> Result<Change, EditError> {
+		match self {
+			Inverse::Graph(frame_index, patch) => {
+				let graph = frame_graph(fold, frame_index)?;
+				let after = graph.clone();
+				patch.restore(graph);
+				return Ok(Change::Graph(graphs_change(&after, graph, frame_index)));
+			},
+			Inverse::Assignments(frame_index, edges, edges_assignment, edges_fold_angle) => {
+				let graph = frame_graph(fold, frame_index)?;
+				edges_assignment.restore(&mut graph.edges_assignment);
+				edges_fold_angle.restore(&mut graph.edges_fold_angle);
+				return Ok(Change::Graph(edges_change(graph, frame_index, edges)));
+			},
+			Inverse::VertexCoordinates(frame_index, vertex_index, coordinates) => {
+				let graph = frame_graph(fold, frame_index)?;
+				let Some(vertex_coordinates) = graph.vertices_coordinates.get_mut(vertex_index) else {
+					return Err(EditError::InvalidVertex(vertex_index));
+				};
+				*vertex_coordinates = coordinates;
+				return Ok(Change::Graph(vertices_change(graph, frame_index, vec![vertex_index])));
+			},
+			// Children the frame got since it was added keep what they inherited from it
+			Inverse::RemoveFrame(frame_index) => {
+				if fold.remove_frame(frame_index).is_none() { return Err(EditError::InvalidFrame(frame_index)); }
+				return Ok(Change::RemovedFrame(frame_index));
+			},
+			// Inserting shifts the parents past the frame back, the frame and its children then get their own links again
+			Inverse::InsertFrame(frame_index, frame, children) => {
+				if frame_index == 0 || frame_index > fold.frames.len() + 1 { return Err(EditError::InvalidFrame(frame_index)); }
+				let parent = frame.parent;
+				fold.insert_frame(frame_index, *frame);
+				fold.frames[frame_index - 1].parent = parent;
+				for (child_index, patch) in children {
+					let child = fold.get_frame_mut(child_index).ok_or(EditError::InvalidFrame(child_index))?;
+					patch.restore(child);
+				}
+				return Ok(Change::InsertedFrame(frame_index));
+			},
+		}
+	}
+}
\ No newline at end of file
@@ -0,0 +1,9 @@
+#[derive(Clone, Copy, Debug)]
+pub enum EditError {
+	InvalidFrame(usize),
+	InvalidVertex(usize),
+	InvalidEdge(usize),
+	NoTransaction,
+	NothingToUndo,
+	NothingToRedo,
+}
\ No newline at end of file
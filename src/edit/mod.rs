@@ -0,0 +1,14 @@
+mod error;
+mod change;
+mod patch;
+mod command;
+mod session;
+
+pub use error::EditError;
+
+pub use change::Change;
+pub use change::GraphChange;
+
+pub use command::Command;
+
+pub use session::EditSession;
\ No newline at end of file
@@ -0,0 +1,163 @@
+use std::collections::BTreeSet;
+
+use crate::fold::{Frame, Graph, EdgeAssignment, EdgeOrder, FaceOrder};
+use crate::real::Real;
+
+// The length of an array before a change and its elements that the change overwrote or removed
+#[derive(Clone, Debug)]
+pub struct ElementsPatch<T> {
+	len: usize,
+	elements: Vec<(usize, T)>,
+}
+
+impl<T: Clone + PartialEq> ElementsPatch<T> {
+	pub fn new(before: &[T], after: &[T]) -> Self {
+		let elements = (0..before.len())
+			.filter(|&index| after.get(index) != Some(&before[index]))
+			.map(|index| (index, before[index].clone()))
+			.collect();
+		return Self { len: before.len(), elements };
+	}
+
+	// For an array that may only grow past its end, restoring truncates it back
+	pub fn appended(before: &[T]) -> Self {
+		return Self { len: before.len(), elements: Vec::new() };
+	}
+
+	// Elements past the end of the array are the ones that were removed, they come in order
+	pub fn restore(self, array: &mut Vec<T>) {
+		array.truncate(self.len);
+		for (index, element) in self.elements {
+			if index < array.len() { array[index] = element; } else { array.push(element); }
+		}
+	}
+}
+
+// What a command touched in a graph, enough to restore it without keeping a copy of the whole graph
+#[derive(Clone, Debug)]
+pub struct GraphPatch {
+	vertices_coordinates: ElementsPatch<Vec<Real>>,
+	vertices_vertices: ElementsPatch<Vec<usize>>,
+	vertices_edges: ElementsPatch<Vec<usize>>,
+	vertices_faces: ElementsPatch<Vec<Option<usize>>>,
+	edges_vertices: ElementsPatch<Vec<usize>>,
+	edges_faces: ElementsPatch<Vec<Option<usize>>>,
+	edges_assignment: ElementsPatch<EdgeAssignment>,
+	edges_fold_angle: ElementsPatch<Real>,
+	edges_length: ElementsPatch<Real>,
+	edge_orders: ElementsPatch<(usize, usize, EdgeOrder)>,
+	faces_vertices: ElementsPatch<Vec<usize>>,
+	faces_edges: ElementsPatch<Vec<usize>>,
+	faces_faces: ElementsPatch<Vec<Option<usize>>>,
+	face_orders: ElementsPatch<(usize, usize, FaceOrder)>,
+	empty_properties: BTreeSet<String>,
+}
+
+impl GraphPatch {
+	pub fn new(before: &Graph, after: &Graph) -> Self {
+		return Self {
+			vertices_coordinates: ElementsPatch::new(&before.vertices_coordinates, &after.vertices_coordinates),
+			vertices_vertices: ElementsPatch::new(&before.vertices_vertices, &after.vertices_vertices),
+			vertices_edges: ElementsPatch::new(&before.vertices_edges, &after.vertices_edges),
+			vertices_faces: ElementsPatch::new(&before.vertices_faces, &after.vertices_faces),
+			edges_vertices: ElementsPatch::new(&before.edges_vertices, &after.edges_vertices),
+			edges_faces: ElementsPatch::new(&before.edges_faces, &after.edges_faces),
+			edges_assignment: ElementsPatch::new(&before.edges_assignment, &after.edges_assignment),
+			edges_fold_angle: ElementsPatch::new(&before.edges_fold_angle, &after.edges_fold_angle),
+			edges_length: ElementsPatch::new(&before.edges_length, &after.edges_length),
+			edge_orders: ElementsPatch::new(&before.edge_orders, &after.edge_orders),
+			faces_vertices: ElementsPatch::new(&before.faces_vertices, &after.faces_vertices),
+			faces_edges: ElementsPatch::new(&before.faces_edges, &after.faces_edges),
+			faces_faces: ElementsPatch::new(&before.faces_faces, &after.faces_faces),
+			face_orders: ElementsPatch::new(&before.face_orders, &after.face_orders),
+			empty_properties: before.empty_properties.clone(),
+		}
+	}
+
+	pub fn appended(before: &Graph) -> Self {
+		return Self {
+			vertices_coordinates: ElementsPatch::appended(&before.vertices_coordinates),
+			vertices_vertices: ElementsPatch::appended(&before.vertices_vertices),
+			vertices_edges: ElementsPatch::appended(&before.vertices_edges),
+			vertices_faces: ElementsPatch::appended(&before.vertices_faces),
+			edges_vertices: ElementsPatch::appended(&before.edges_vertices),
+			edges_faces: ElementsPatch::appended(&before.edges_faces),
+			edges_assignment: ElementsPatch::appended(&before.edges_assignment),
+			edges_fold_angle: ElementsPatch::appended(&before.edges_fold_angle),
+			edges_length: ElementsPatch::appended(&before.edges_length),
+			edge_orders: ElementsPatch::appended(&before.edge_orders),
+			faces_vertices: ElementsPatch::appended(&before.faces_vertices),
+			faces_edges: ElementsPatch::appended(&before.faces_edges),
+			faces_faces: ElementsPatch::appended(&before.faces_faces),
+			face_orders: ElementsPatch::appended(&before.face_orders),
+			empty_properties: before.empty_properties.clone(),
+		}
+	}
+
+	pub fn restore(self, graph: &mut Graph) {
+		self.vertices_coordinates.restore(&mut graph.vertices_coordinates);
+		self.vertices_vertices.restore(&mut graph.vertices_vertices);
+		self.vertices_edges.restore(&mut graph.vertices_edges);
+		self.vertices_faces.restore(&mut graph.vertices_faces);
+		self.edges_vertices.restore(&mut graph.edges_vertices);
+		self.edges_faces.restore(&mut graph.edges_faces);
+		self.edges_assignment.restore(&mut graph.edges_assignment);
+		self.edges_fold_angle.restore(&mut graph.edges_fold_angle);
+		self.edges_length.restore(&mut graph.edges_length);
+		self.edge_orders.restore(&mut graph.edge_orders);
+		self.faces_vertices.restore(&mut graph.faces_vertices);
+		self.faces_edges.restore(&mut graph.faces_edges);
+		self.faces_faces.restore(&mut graph.faces_faces);
+		self.face_orders.restore(&mut graph.face_orders);
+		graph.empty_properties = self.empty_properties;
+	}
+}
+
+// What removing its parent changes in a child frame: its links, and when it inherits, the properties it left empty
+// and the custom data it did not have, so restoring truncates them back instead of keeping a copy of the frame
+#[derive(Clone, Debug)]
+pub struct ChildPatch {
+	parent: Option<usize>,
+	inherit: bool,
+	author: usize,
+	title: usize,
+	description: usize,
+	unit: usize,
+	classes: ElementsPatch<String>,
+	attributes: ElementsPatch<String>,
+	custom_keys: BTreeSet<String>,
+	empty_properties: BTreeSet<String>,
+	graph: GraphPatch,
+}
+
+impl ChildPatch {
+	pub fn new(before: &Frame) -> Self {
+		return Self {
+			parent: before.parent,
+			inherit: before.inherit,
+			author: before.author.len(),
+			title: before.title.len(),
+			description: before.description.len(),
+			unit: before.unit.len(),
+			classes: ElementsPatch::appended(&before.classes),
+			attributes: ElementsPatch::appended(&before.attributes),
+			custom_keys: before.custom_data.keys().cloned().collect(),
+			empty_properties: before.empty_properties.clone(),
+			graph: GraphPatch::appended(&before.graph),
+		}
+	}
+
+	pub fn restore(self, frame: &mut Frame) {
+		frame.parent = self.parent;
+		frame.inherit = self.inherit;
+		frame.author.truncate(self.author);
+		frame.title.truncate(self.title);
+		frame.description.truncate(self.description);
+		frame.unit.truncate(self.unit);
+		self.classes.restore(&mut frame.classes);
+		self.attributes.restore(&mut frame.attributes);
+		frame.custom_data.retain(|key, _| self.custom_keys.contains(key));
+		frame.empty_properties = self.empty_properties;
+		self.graph.restore(&mut frame.graph);
+	}
+}
\ No newline at end of file
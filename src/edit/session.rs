@@ -0,0 +1,202 @@
+use crate::fold::Fold;
+use crate::graph::spatial::SpatialIndex;
+use super::change::Change;
+use super::command::{Command, Inverse};
+use super::error::EditError;
+
+#[derive(Default, Clone, Debug)]
+struct Transaction {
+	title: String,
+	commands: Vec<(Command, Inverse)>,
+}
+
+// Every command goes through the session, grouped into transactions that are undone and redone as a whole
+#[derive(Default, Clone, Debug)]
+pub struct EditSession {
+	fold: Fold,
+	undo_stack: Vec<Transaction>,
+	redo_stack: Vec<Transaction>,
+	transaction: Option<Transaction>,
+	spatial_index: Option<(usize, SpatialIndex)>,
+}
+
+fn command_index<'a>(spatial_index: &'a Option<(usize, SpatialIndex)>, command: &Command) -> Option<&'a SpatialIndex> {
+	return spatial_index.as_ref()
+		.filter(|(frame_index, _)| command.frame_index() == Some(*frame_index))
+		.map(|(_, index)| index);
+}
+
+impl EditSession {
+	pub fn new(fold: Fold) -> Self {
+		return Self { fold, ..Default::default() }
+	}
+
+	// The fold is only changed through commands, so that every inverse stays valid
+	pub fn fold(&self) -> &Fold {
+		return &self.fold;
+	}
+
+	pub fn into_fold(self) -> Fold {
+		return self.fold;
+	}
+
+	// The index follows every change of the frame, frames inserted or removed before it shift its index
+	pub fn enable_spatial_index(&mut self, frame_index: usize) -> Result<(), EditError> {
+		let frame = self.fold.get_frame(frame_index).ok_or(EditError::InvalidFrame(frame_index))?;
+		self.spatial_index = Some((frame_index, SpatialIndex::new(&frame.graph)));
+		return Ok(());
+	}
+
+	pub fn disable_spatial_index(&mut self) {
+		self.spatial_index = None;
+	}
+
+	pub fn spatial_index(&self) -> Option<(usize, &SpatialIndex)> {
+		return self.spatial_index.as_ref().map(|(frame_index, index)| (*frame_index, index));
+	}
+
+	fn update_spatial_index(&mut self, change: &Change) {
+		let Some((frame_index, index)) = self.spatial_index.as_mut() else { return; };
+		match change {
+			Change::Graph(graph_change) => {
+				if graph_change.frame_index != *frame_index { return; }
+				let Some(frame) = self.fold.get_frame(*frame_index) else { return; };
+				index.apply_change(&frame.graph, graph_change);
+				return;
+			},
+			Change::InsertedFrame(inserted_index) => if *frame_index >= *inserted_index { *frame_index += 1; },
+			Change::RemovedFrame(removed_index) => {
+				if *frame_index == *removed_index {
+					self.spatial_index = None;
+					return;
+				}
+				if *frame_index > *removed_index { *frame_index -= 1; }
+			},
+		}
+		// Removing a frame may give its children what they inherited from it, so the graph is indexed again
+		match self.fold.get_frame(*frame_index) {
+			Some(frame) => *index = SpatialIndex::with_cell_size(&frame.graph, index.cell_size),
+			None => self.spatial_index = None,
+		}
+	}
+
+	pub fn execute(&mut self, command: Command) -> Result<Change, EditError> {
+		let (change, inverse) = command.apply(&mut self.fold, command_index(&self.spatial_index, &command))?;
+		self.update_spatial_index(&change);
+		self.redo_stack.clear();
+		match self.transaction.as_mut() {
+			Some(transaction) => transaction.commands.push((command, inverse)),
+			None => self.undo_stack.push(Transaction { title: String::new(), commands: vec![(command, inverse)] }),
+		}
+		return Ok(change);
+	}
+
+	// Transactions do not nest, beginning one while another is open commits the open one first
+	pub fn begin_transaction(&mut self, title: &str) {
+		self.commit_transaction().ok();
+		self.transaction = Some(Transaction { title: String::from(title), commands: Vec::new() });
+	}
+
+	pub fn commit_transaction(&mut self) -> Result<(), EditError> {
+		let transaction = self.transaction.take().ok_or(EditError::NoTransaction)?;
+		if transaction.commands.len() > 0 { self.undo_stack.push(transaction); }
+		return Ok(());
+	}
+
+	// Stops at the first inverse that fails, the commands left stay in the open transaction
+	pub fn cancel_transaction(&mut self) -> Result<Vec<Change>, EditError> {
+		let mut transaction = self.transaction.take().ok_or(EditError::NoTransaction)?;
+		let mut changes: Vec<Change> = Vec::new();
+		while let Some((command, inverse)) = transaction.commands.pop() {
+			match inverse.clone().apply(&mut self.fold) {
+				Ok(change) => {
+					self.update_spatial_index(&change);
+					changes.push(change);
+				},
+				Err(error) => {
+					transaction.commands.push((command, inverse));
+					self.transaction = Some(transaction);
+					return Err(error);
+				},
+			}
+		}
+		return Ok(changes);
+	}
+
+	pub fn is_in_transaction(&self) -> bool {
+		return self.transaction.is_some();
+	}
+
+	pub fn can_undo(&self) -> bool {
+		return self.undo_stack.len() > 0;
+	}
+
+	pub fn can_redo(&self) -> bool {
+		return self.redo_stack.len() > 0;
+	}
+
+	pub fn undo_title(&self) -> Option<&str> {
+		return self.undo_stack.last().map(|transaction| transaction.title.as_str());
+	}
+
+	pub fn redo_title(&self) -> Option<&str> {
+		return self.redo_stack.last().map(|transaction| transaction.title.as_str());
+	}
+
+	// Stops at the first inverse that fails, the commands undone until then move to the redo stack
+	// and the ones left stay on the undo stack
+	pub fn undo(&mut self) -> Result<Vec<Change>, EditError> {
+		self.commit_transaction().ok();
+		let mut transaction = self.undo_stack.pop().ok_or(EditError::NothingToUndo)?;
+		let mut changes: Vec<Change> = Vec::new();
+		let mut commands: Vec<(Command, Inverse)> = Vec::new();
+		let mut result: Result<(), EditError> = Ok(());
+		while let Some((command, inverse)) = transaction.commands.pop() {
+			match inverse.clone().apply(&mut self.fold) {
+				Ok(change) => {
+					self.update_spatial_index(&change);
+					changes.push(change);
+					commands.push((command, inverse));
+				},
+				Err(error) => {
+					transaction.commands.push((command, inverse));
+					result = Err(error);
+					break;
+				},
+			}
+		}
+		commands.reverse();
+		if commands.len() > 0 { self.redo_stack.push(Transaction { title: transaction.title.clone(), commands }); }
+		if transaction.commands.len() > 0 { self.undo_stack.push(transaction); }
+		return result.map(|_| changes);
+	}
+
+	// Commands are applied again, their inverses are computed anew, the first that fails stops the redo
+	// with the commands redone until then on the undo stack and the ones left on the redo stack
+	pub fn redo(&mut self) -> Result<Vec<Change>, EditError> {
+		self.commit_transaction().ok();
+		let mut transaction = self.redo_stack.pop().ok_or(EditError::NothingToRedo)?;
+		let mut changes: Vec<Change> = Vec::new();
+		let mut commands: Vec<(Command, Inverse)> = Vec::new();
+		let mut result: Result<(), EditError> = Ok(());
+		let mut pending = std::mem::take(&mut transaction.commands).into_iter();
+		for (command, inverse) in pending.by_ref() {
+			match command.apply(&mut self.fold, command_index(&self.spatial_index, &command)) {
+				Ok((change, new_inverse)) => {
+					self.update_spatial_index(&change);
+					changes.push(change);
+					commands.push((command, new_inverse));
+				},
+				Err(error) => {
+					transaction.commands.push((command, inverse));
+					result = Err(error);
+					break;
+				},
+			}
+		}
+		transaction.commands.extend(pending);
+		if commands.len() > 0 { self.undo_stack.push(Transaction { title: transaction.title.clone(), commands }); }
+		if transaction.commands.len() > 0 { self.redo_stack.push(transaction); }
+		return result.map(|_| changes);
+	}
+}
\ No newline at end of file
@@ -3,6 +3,22 @@ use serde::{Serialize, Deserialize};
 use super::frame::Frame;
 use super::validation::Error;
 use super::validation::validate_frame_parents;
+use super::validation::ParentError;
+use super::tree::{FrameTree, make_frame_tree, frame_children, frame_ancestors, frame_roots, frames_topological_order};
+use super::tree::{insert_frame, remove_frame, move_frame};
+use super::migration::{MigrationChange, MigrationError, migrate_fold, read_fold, write_fold};
+use crate::graph::diff::{GraphDiff, make_diff_frame};
+use crate::format::svg::{SvgError, SvgImportOptions, import_svg};
+use crate::format::oripa::{OripaError, import_cp, export_cp, import_opx, export_opx};
+use crate::format::obj::{ObjError, import_obj};
+use crate::format::dxf::{DxfError, import_dxf};
+use crate::format::gltf::{GltfError, GltfOptions, export_gltf, export_glb};
+#[cfg(any(feature = "cbor", feature = "msgpack"))]
+use crate::format::binary::BinaryError;
+#[cfg(feature = "cbor")]
+use crate::format::binary::{import_cbor, export_cbor};
+#[cfg(feature = "msgpack")]
+use crate::format::binary::{import_msgpack, export_msgpack};
 
 #[derive(Clone, Serialize, Deserialize, Debug)]
 #[serde(default)]
@@ -70,6 +86,78 @@ impl Fold {
 		return Self { ..Default::default() }
 	}
 
+	pub fn from_json(json: &str) -> Result<(Self, Vec<MigrationChange>), MigrationError> {
+		return read_fold(json);
+	}
+
+	pub fn to_json(&self, version: f64) -> Result<String, MigrationError> {
+		return write_fold(self, version);
+	}
+
+	pub fn migrate_to(&mut self, version: f64) -> Result<Vec<MigrationChange>, MigrationError> {
+		return migrate_fold(self, version);
+	}
+
+	pub fn from_svg(svg: &str) -> Result<Self, SvgError> {
+		return import_svg(svg, &SvgImportOptions::default());
+	}
+
+	pub fn from_svg_with_options(svg: &str, options: &SvgImportOptions) -> Result<Self, SvgError> {
+		return import_svg(svg, options);
+	}
+
+	pub fn from_cp(cp: &str) -> Result<Self, OripaError> {
+		return import_cp(cp);
+	}
+
+	pub fn to_cp(&self) -> String {
+		return export_cp(&self.key_frame.graph);
+	}
+
+	pub fn from_opx(opx: &str) -> Result<Self, OripaError> {
+		return import_opx(opx);
+	}
+
+	pub fn to_opx(&self) -> String {
+		return export_opx(self);
+	}
+
+	pub fn from_obj(obj: &str) -> Result<Self, ObjError> {
+		return import_obj(obj);
+	}
+
+	pub fn from_dxf(dxf: &str) -> Result<Self, DxfError> {
+		return import_dxf(dxf);
+	}
+
+	pub fn to_gltf(&self, options: &GltfOptions) -> Result<String, GltfError> {
+		return export_gltf(self, options);
+	}
+
+	pub fn to_glb(&self, options: &GltfOptions) -> Result<Vec<u8>, GltfError> {
+		return export_glb(self, options);
+	}
+
+	#[cfg(feature = "cbor")]
+	pub fn from_cbor(bytes: &[u8]) -> Result<Self, BinaryError> {
+		return import_cbor(bytes);
+	}
+
+	#[cfg(feature = "cbor")]
+	pub fn to_cbor(&self, packed: bool) -> Result<Vec<u8>, BinaryError> {
+		return export_cbor(self, packed);
+	}
+
+	#[cfg(feature = "msgpack")]
+	pub fn from_msgpack(bytes: &[u8]) -> Result<Self, BinaryError> {
+		return import_msgpack(bytes);
+	}
+
+	#[cfg(feature = "msgpack")]
+	pub fn to_msgpack(&self, packed: bool) -> Result<Vec<u8>, BinaryError> {
+		return export_msgpack(self, packed);
+	}
+
 	pub fn get_frame(&self, frame_index: usize) -> Option<&Frame> {
 		if frame_index == 0 {
 			return Some(&self.key_frame);
@@ -80,6 +168,16 @@ impl Fold {
 		}
 	}
 
+	pub fn get_frame_mut(&mut self, frame_index: usize) -> Option<&mut Frame> {
+		if frame_index == 0 {
+			return Some(&mut self.key_frame);
+		} else if frame_index - 1 < self.frames.len() {
+			return Some(&mut self.frames[frame_index - 1]);
+		} else {
+			return None;
+		}
+	}
+
 	pub fn get_inherited_frame(&self, frame_index: usize) -> Result<Frame, &Frame> {
 		let mut frame = self.get_frame(frame_index).unwrap();
 		if !frame.inherit { return Err(frame); }
@@ -91,11 +189,12 @@ impl Fold {
 			None => return Err(frame)
 		}
 
+		// Bounded so that parent cycles, which validate reports, cannot hang it
 		let mut inherited_frame = frame.clone();
-		loop {
+		for _ in 0..=self.frames.len() {
 			match frame.parent {
 				Some(index) => {
-					let frame_parent = self.get_frame(index).unwrap();
+					let Some(frame_parent) = self.get_frame(index) else { break; };
 					inherited_frame.inherit_properties(frame_parent);
 					if !frame_parent.inherit { break; }
 					frame = frame_parent;
@@ -107,6 +206,14 @@ impl Fold {
 		return Ok(inherited_frame);
 	}
 
+	pub fn get_flat_frame(&self, frame_index: usize) -> Option<Frame> {
+		self.get_frame(frame_index)?;
+		return match self.get_inherited_frame(frame_index) {
+			Ok(inherited_frame) => Some(inherited_frame),
+			Err(frame) => Some(frame.clone()),
+		};
+	}
+
 	pub fn get_inherited_frames(&self) -> Vec<Result<Frame, &Frame>> {
 		let mut inherited_frames: Vec<Result<Frame, &Frame>> = Vec::new();
 
@@ -118,6 +225,86 @@ impl Fold {
 		return inherited_frames;
 	}
 
+	pub fn children(&self, frame_index: usize) -> Vec<usize> {
+		return frame_children(self, frame_index);
+	}
+
+	pub fn ancestors(&self, frame_index: usize) -> Vec<usize> {
+		return frame_ancestors(self, frame_index);
+	}
+
+	pub fn roots(&self) -> Vec<usize> {
+		return frame_roots(self);
+	}
+
+	pub fn topological_order(&self) -> Result<Vec<usize>, ParentError> {
+		return frames_topological_order(self);
+	}
+
+	pub fn frame_tree(&self) -> FrameTree {
+		return make_frame_tree(self);
+	}
+
+	pub fn insert_frame(&mut self, frame_index: usize, frame: Frame) {
+		insert_frame(self, frame_index, frame);
+	}
+
+	pub fn remove_frame(&mut self, frame_index: usize) -> Option<Frame> {
+		return remove_frame(self, frame_index);
+	}
+
+	pub fn move_frame(&mut self, from_index: usize, to_index: usize) {
+		move_frame(self, from_index, to_index);
+	}
+
+	// Compares the key frames of the two files
+	pub fn diff(&self, fold: &Fold) -> GraphDiff {
+		return self.key_frame.graph.diff(&fold.key_frame.graph);
+	}
+
+	// Appends a frame showing the changes from frame a to frame b, as a child of a
+	pub fn push_diff_frame(&mut self, a_index: usize, b_index: usize) -> Option<usize> {
+		let a = self.get_flat_frame(a_index)?;
+		let b = self.get_flat_frame(b_index)?;
+		let diff = a.graph.diff(&b.graph);
+		self.frames.push(make_diff_frame(&a.graph, &b.graph, &diff, Some(a_index)));
+		return Some(self.frames.len());
+	}
+
+	// Files with missing or cyclic parents are left as they are
+	pub fn flatten(&mut self) -> Result<(), ParentError> {
+		for frame_index in 0..(1 + self.frames.len()) {
+			validate_frame_parents(self, frame_index)?;
+		}
+		let flat_frames: Vec<Frame> = (0..(1 + self.frames.len()))
+			.map(|frame_index| self.get_flat_frame(frame_index).unwrap())
+			.collect();
+
+		for (frame_index, mut frame) in flat_frames.into_iter().enumerate() {
+			frame.inherit = false;
+			if frame_index == 0 { self.key_frame = frame; } else { self.frames[frame_index - 1] = frame; }
+		}
+		return Ok(());
+	}
+
+	// Frames keep only what differs from their parent, those that cannot be expressed this way stay flat
+	pub fn deflate(&mut self) -> Result<(), ParentError> {
+		self.flatten()?;
+		let flat_fold = self.clone();
+
+		for frame_index in 1..(1 + self.frames.len()) {
+			let frame = &mut self.frames[frame_index - 1];
+			let Some(parent_index) = frame.parent else { continue; };
+			if parent_index == frame_index { continue; }
+			let Some(parent) = flat_fold.get_frame(parent_index) else { continue; };
+			if !frame.can_inherit_from(parent) { continue; }
+
+			frame.deflate_properties(parent);
+			frame.inherit = true;
+		}
+		return Ok(());
+	}
+
 	pub fn validate(&self) -> Result<(), Error> {
 		for frame_index in 0..(1 + self.frames.len()) {
 			validate_frame_parents(self, frame_index)?;
@@ -1,11 +1,24 @@
-use serde::{Serialize, Deserialize};
+use std::collections::BTreeSet;
+
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+use serde::ser;
 use serde_json::{Map, Value};
 
-use super::graph::Graph;
+use super::graph::{Graph, GraphFields};
 use super::validation::Error;
+use crate::format::svg::{SvgExportOptions, export_svg};
+use crate::format::obj::export_obj;
+use crate::format::dxf::export_dxf;
+use crate::graph::symmetry::{Symmetry, SymmetryTransform, SymmetryViolation, SYMMETRY_KEY, check_symmetry};
+use crate::graph::transform::{TransformError, transform_graph, scale_graph, unit_scale};
+use crate::real::Real;
+use crate::format::solid::{SolidOptions, export_stl_ascii, export_stl_binary, export_ply};
+
+const STRING_KEYS: [&str; 4] = ["frame_author", "frame_title", "frame_description", "frame_unit"];
 
-#[derive(Default, Clone, Serialize, Deserialize, Debug)]
-#[serde(default)]
+// The derived serialization is wrapped below to write back properties given as empty values
+#[derive(Default, Clone, Serialize, Debug)]
+#[serde(remote = "Self")]
 pub struct Frame {
 	#[serde(rename = "frame_author")]
 	#[serde(skip_serializing_if = "String::is_empty")]
@@ -44,6 +57,80 @@ pub struct Frame {
 
 	#[serde(flatten)]
 	pub custom_data: Map<String, Value>,
+
+	// Keys given as empty values, they override the parent instead of inheriting from it
+	#[serde(skip)]
+	pub empty_properties: BTreeSet<String>,
+}
+
+impl Serialize for Frame {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		if self.empty_properties.is_empty() && self.graph.empty_properties.is_empty() {
+			return Frame::serialize(self, serializer);
+		}
+
+		let mut value = Frame::serialize(self, serde_json::value::Serializer).map_err(ser::Error::custom)?;
+		if let Value::Object(object) = &mut value {
+			for key in self.empty_properties.iter().chain(self.graph.empty_properties.iter()) {
+				if object.contains_key(key) { continue; }
+				let empty_value = if STRING_KEYS.contains(&key.as_str()) { Value::String(String::new()) } else { Value::Array(Vec::new()) };
+				object.insert(key.clone(), empty_value);
+			}
+		}
+		return value.serialize(serializer);
+	}
+}
+
+// Properties read as options, like GraphFields, to keep track of those given as empty values
+#[derive(Deserialize)]
+struct FrameFields {
+	frame_author: Option<String>,
+	frame_title: Option<String>,
+	frame_description: Option<String>,
+	frame_classes: Option<Vec<String>>,
+	frame_attributes: Option<Vec<String>>,
+	frame_unit: Option<String>,
+
+	#[serde(flatten)]
+	graph: GraphFields,
+
+	frame_parent: Option<usize>,
+	frame_inherit: Option<bool>,
+
+	#[serde(flatten)]
+	custom_data: Map<String, Value>,
+}
+
+fn take_string(property: Option<String>, key: &str, empty_properties: &mut BTreeSet<String>) -> String {
+	if property.as_ref().is_some_and(|property| property.is_empty()) { empty_properties.insert(String::from(key)); }
+	return property.unwrap_or_default();
+}
+
+fn take_strings(property: Option<Vec<String>>, key: &str, empty_properties: &mut BTreeSet<String>) -> Vec<String> {
+	if property.as_ref().is_some_and(|property| property.is_empty()) { empty_properties.insert(String::from(key)); }
+	return property.unwrap_or_default();
+}
+
+impl<'de> Deserialize<'de> for Frame {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let fields = FrameFields::deserialize(deserializer)?;
+		let mut empty_properties: BTreeSet<String> = BTreeSet::new();
+		let mut frame = Frame {
+			author: take_string(fields.frame_author, "frame_author", &mut empty_properties),
+			title: take_string(fields.frame_title, "frame_title", &mut empty_properties),
+			description: take_string(fields.frame_description, "frame_description", &mut empty_properties),
+			classes: take_strings(fields.frame_classes, "frame_classes", &mut empty_properties),
+			attributes: take_strings(fields.frame_attributes, "frame_attributes", &mut empty_properties),
+			unit: take_string(fields.frame_unit, "frame_unit", &mut empty_properties),
+			graph: fields.graph.into_graph(),
+			parent: fields.frame_parent,
+			inherit: fields.frame_inherit.unwrap_or_default(),
+			custom_data: fields.custom_data,
+			empty_properties: BTreeSet::new(),
+		};
+		frame.empty_properties = empty_properties;
+		return Ok(frame);
+	}
 }
 
 #[derive(Clone, Copy, Serialize, Deserialize, Debug)]
@@ -123,22 +210,24 @@ impl Frame {
 
 	pub fn inherit_properties(&mut self, frame: &Self) {
 		macro_rules! inherit_property {
-			($s: expr, $f: expr, $p: ident) => {
-				if $s.$p.is_empty() { $s.$p = $f.$p.clone(); }
+			($s: expr, $f: expr, $p: ident, $k: expr) => {
+				if $s.$p.is_empty() && !$s.empty_properties.contains($k) {
+					$s.$p = $f.$p.clone();
+					if $f.empty_properties.contains($k) { $s.empty_properties.insert(String::from($k)); }
+				}
 			};
 		}
 
-		inherit_property!(self, frame, author);
-		inherit_property!(self, frame, title);
-		inherit_property!(self, frame, description);
-		inherit_property!(self, frame, classes);
-		inherit_property!(self, frame, attributes);
-		inherit_property!(self, frame, unit);
+		inherit_property!(self, frame, author, "frame_author");
+		inherit_property!(self, frame, title, "frame_title");
+		inherit_property!(self, frame, description, "frame_description");
+		inherit_property!(self, frame, classes, "frame_classes");
+		inherit_property!(self, frame, attributes, "frame_attributes");
+		inherit_property!(self, frame, unit, "frame_unit");
 
 		self.graph.inherit_properties(&frame.graph);
 
 		if self.parent.is_none() { self.parent = frame.parent; }
-		self.inherit = frame.inherit;
 
 		for (key, value) in frame.custom_data.iter() {
 			if !self.custom_data.contains_key(key) {
@@ -147,6 +236,118 @@ impl Frame {
 		}
 	}
 
+	pub fn deflate_properties(&mut self, frame: &Self) {
+		macro_rules! deflate_property {
+			($s: expr, $f: expr, $p: ident, $k: expr) => {
+				if $s.$p == $f.$p {
+					$s.$p.clear();
+					$s.empty_properties.remove($k);
+				}
+			};
+		}
+
+		deflate_property!(self, frame, author, "frame_author");
+		deflate_property!(self, frame, title, "frame_title");
+		deflate_property!(self, frame, description, "frame_description");
+		deflate_property!(self, frame, classes, "frame_classes");
+		deflate_property!(self, frame, attributes, "frame_attributes");
+		deflate_property!(self, frame, unit, "frame_unit");
+
+		self.graph.deflate_properties(&frame.graph);
+
+		self.custom_data.retain(|key, value| frame.custom_data.get(key) != Some(value));
+	}
+
+	pub fn can_inherit_from(&self, frame: &Self) -> bool {
+		macro_rules! can_inherit_property {
+			($s: expr, $f: expr, $p: ident, $k: expr) => {
+				!$s.$p.is_empty() || $f.$p.is_empty() || $s.empty_properties.contains($k)
+			};
+		}
+
+		return can_inherit_property!(self, frame, author, "frame_author")
+			&& can_inherit_property!(self, frame, title, "frame_title")
+			&& can_inherit_property!(self, frame, description, "frame_description")
+			&& can_inherit_property!(self, frame, classes, "frame_classes")
+			&& can_inherit_property!(self, frame, attributes, "frame_attributes")
+			&& can_inherit_property!(self, frame, unit, "frame_unit")
+			&& self.graph.can_inherit_from(&frame.graph)
+			&& frame.custom_data.keys().all(|key| self.custom_data.contains_key(key));
+	}
+
+	pub fn to_svg(&self, options: &SvgExportOptions) -> String {
+		return export_svg(self, options);
+	}
+
+	pub fn to_obj(&self, crease_lines: bool) -> String {
+		return export_obj(self, crease_lines);
+	}
+
+	pub fn to_stl(&self, options: &SolidOptions) -> String {
+		return export_stl_ascii(self, options);
+	}
+
+	pub fn to_stl_binary(&self, options: &SolidOptions) -> Vec<u8> {
+		return export_stl_binary(self, options);
+	}
+
+	pub fn to_ply(&self, options: &SolidOptions) -> String {
+		return export_ply(self, options);
+	}
+
+	pub fn to_dxf(&self) -> String {
+		return export_dxf(self);
+	}
+
+	pub fn symmetries(&self) -> Vec<Symmetry> {
+		let Some(value) = self.custom_data.get(SYMMETRY_KEY) else { return Vec::new(); };
+		return serde_json::from_value(value.clone()).unwrap_or_default();
+	}
+
+	pub fn set_symmetries(&mut self, symmetries: &[Symmetry]) {
+		if symmetries.len() == 0 {
+			self.custom_data.remove(SYMMETRY_KEY);
+			return;
+		}
+		let value = serde_json::to_value(symmetries).unwrap_or(Value::Null);
+		self.custom_data.insert(String::from(SYMMETRY_KEY), value);
+	}
+
+	// Edges of the graph whose images under the declared symmetries are missing or assigned otherwise
+	pub fn check_symmetry(&self) -> Vec<SymmetryViolation> {
+		return check_symmetry(&self.graph, &self.symmetries());
+	}
+
+	// The matrix is homogeneous, of size 3 for 2D transforms and 4 for 3D ones
+	// Declared symmetries follow the sheet, those the transform does not preserve are removed and returned
+	pub fn transform(&mut self, matrix: &[Vec<Real>]) -> Result<Vec<Symmetry>, TransformError> {
+		transform_graph(&mut self.graph, matrix)?;
+		let symmetries = self.symmetries();
+		if symmetries.len() == 0 { return Ok(Vec::new()); }
+		match SymmetryTransform::from_homogeneous(matrix).filter(|transform| transform.is_similarity()) {
+			Some(transform) => {
+				let symmetries: Vec<Symmetry> = symmetries.iter().map(|symmetry| symmetry.transformed(&transform)).collect();
+				self.set_symmetries(&symmetries);
+				return Ok(Vec::new());
+			},
+			None => {
+				self.set_symmetries(&[]);
+				return Ok(symmetries);
+			},
+		}
+	}
+
+	// Frames without a unit, or in the arbitrary unit, cannot be converted
+	pub fn convert_unit(&mut self, unit: FrameUnit) -> Result<(), TransformError> {
+		let from: FrameUnit = serde_json::from_value(Value::String(self.unit.clone())).map_err(|_| TransformError::UnknownUnit)?;
+		let scale = unit_scale(from, unit).ok_or(TransformError::UnknownUnit)?;
+		scale_graph(&mut self.graph, &scale);
+		let symmetries: Vec<Symmetry> = self.symmetries().iter().map(|symmetry| symmetry.transformed(&SymmetryTransform::scale(&scale))).collect();
+		self.set_symmetries(&symmetries);
+		if let Value::String(unit) = serde_json::to_value(unit).unwrap_or(Value::Null) { self.unit = unit; }
+		return Ok(());
+	}
+
 	pub fn validate(&self) -> Result<(), Error> {
 		self.graph.validate()?;
 		return Ok(());
@@ -1,15 +1,25 @@
+use std::collections::BTreeSet;
+
 use serde::{Deserialize, Serialize};
 use serde_repr::{Serialize_repr, Deserialize_repr};
 
 use crate::real::Real;
 use super::validation::Error;
 use crate::graph::validation;
+use crate::format::binary::{serialize_coordinates, deserialize_coordinates, deserialize_optional_coordinates};
+use crate::graph::arrangement;
+use crate::graph::diff::{self, GraphDiff};
+use crate::graph::merge::{self, GraphMerge};
+use crate::graph::spatial::SpatialIndex;
+use crate::graph::snap::{self, SnapCandidate, SnapOptions};
+use crate::geometry::{Point, Line};
 
 #[derive(Default, Clone, Serialize, Deserialize, Debug)]
 #[serde(default)]
 pub struct Graph {
 	#[serde(rename = "vertices_coords")]
 	#[serde(skip_serializing_if = "Vec::is_empty")]
+	#[serde(serialize_with = "serialize_coordinates", deserialize_with = "deserialize_coordinates")]
 	pub vertices_coordinates: Vec<Vec<Real>>,
 
 	#[serde(skip_serializing_if = "Vec::is_empty")]
@@ -55,9 +65,74 @@ pub struct Graph {
 	#[serde(rename = "faceOrders")]
 	#[serde(skip_serializing_if = "Vec::is_empty")]
 	pub face_orders: Vec<(usize, usize, FaceOrder)>,
+
+	// Keys given as empty arrays, they override the parent instead of inheriting from it
+	#[serde(skip)]
+	pub empty_properties: BTreeSet<String>,
 }
 
-#[derive(Clone, Copy, Serialize, Deserialize, Debug)]
+// Properties read as options, so that keys given as empty arrays are told apart from missing ones in a single pass
+#[derive(Deserialize)]
+pub struct GraphFields {
+	#[serde(rename = "vertices_coords")]
+	#[serde(default, deserialize_with = "deserialize_optional_coordinates")]
+	vertices_coordinates: Option<Vec<Vec<Real>>>,
+	vertices_vertices: Option<Vec<Vec<usize>>>,
+	vertices_edges: Option<Vec<Vec<usize>>>,
+	vertices_faces: Option<Vec<Vec<Option<usize>>>>,
+	edges_vertices: Option<Vec<Vec<usize>>>,
+	edges_faces: Option<Vec<Vec<Option<usize>>>>,
+	edges_assignment: Option<Vec<EdgeAssignment>>,
+
+	#[serde(rename = "edges_foldAngle")]
+	#[serde(alias = "edges_foldAngles")]
+	edges_fold_angle: Option<Vec<Real>>,
+
+	#[serde(alias = "edges_lengths")]
+	edges_length: Option<Vec<Real>>,
+
+	#[serde(rename = "edgeOrders")]
+	edge_orders: Option<Vec<(usize, usize, EdgeOrder)>>,
+
+	faces_vertices: Option<Vec<Vec<usize>>>,
+	faces_edges: Option<Vec<Vec<usize>>>,
+	faces_faces: Option<Vec<Vec<Option<usize>>>>,
+
+	#[serde(rename = "faceOrders")]
+	face_orders: Option<Vec<(usize, usize, FaceOrder)>>,
+}
+
+fn take_property<T>(property: Option<Vec<T>>, key: &str, empty_properties: &mut BTreeSet<String>) -> Vec<T> {
+	if property.as_ref().is_some_and(|property| property.is_empty()) { empty_properties.insert(String::from(key)); }
+	return property.unwrap_or_default();
+}
+
+impl GraphFields {
+	pub fn into_graph(self) -> Graph {
+		let mut empty_properties: BTreeSet<String> = BTreeSet::new();
+		let mut graph = Graph {
+			vertices_coordinates: take_property(self.vertices_coordinates, "vertices_coords", &mut empty_properties),
+			vertices_vertices: take_property(self.vertices_vertices, "vertices_vertices", &mut empty_properties),
+			vertices_edges: take_property(self.vertices_edges, "vertices_edges", &mut empty_properties),
+			vertices_faces: take_property(self.vertices_faces, "vertices_faces", &mut empty_properties),
+			edges_vertices: take_property(self.edges_vertices, "edges_vertices", &mut empty_properties),
+			edges_faces: take_property(self.edges_faces, "edges_faces", &mut empty_properties),
+			edges_assignment: take_property(self.edges_assignment, "edges_assignment", &mut empty_properties),
+			edges_fold_angle: take_property(self.edges_fold_angle, "edges_foldAngle", &mut empty_properties),
+			edges_length: take_property(self.edges_length, "edges_length", &mut empty_properties),
+			edge_orders: take_property(self.edge_orders, "edgeOrders", &mut empty_properties),
+			faces_vertices: take_property(self.faces_vertices, "faces_vertices", &mut empty_properties),
+			faces_edges: take_property(self.faces_edges, "faces_edges", &mut empty_properties),
+			faces_faces: take_property(self.faces_faces, "faces_faces", &mut empty_properties),
+			face_orders: take_property(self.face_orders, "faceOrders", &mut empty_properties),
+			empty_properties: BTreeSet::new(),
+		};
+		graph.empty_properties = empty_properties;
+		return graph;
+	}
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Debug)]
 pub enum EdgeAssignment {
 	#[serde(rename = "B")]
 	Boundary,
@@ -75,7 +150,7 @@ pub enum EdgeAssignment {
 	Join,
 }
 
-#[derive(Clone, Copy, Serialize_repr, Deserialize_repr, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Serialize_repr, Deserialize_repr, Debug)]
 #[repr(i8)]
 pub enum EdgeOrder {
 	Left = 1,
@@ -83,7 +158,7 @@ pub enum EdgeOrder {
 	Unknown = 0,
 }
 
-#[derive(Clone, Copy, Serialize_repr, Deserialize_repr, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Serialize_repr, Deserialize_repr, Debug)]
 #[repr(i8)]
 pub enum FaceOrder {
 	Above = 1,
@@ -98,25 +173,108 @@ impl Graph {
 
 	pub fn inherit_properties(&mut self, graph: &Self) {
 		macro_rules! inherit_property {
-			($s: expr, $g: expr, $p: ident) => {
-				if $s.$p.is_empty() { $s.$p = $g.$p.clone(); }
+			($s: expr, $g: expr, $p: ident, $k: expr) => {
+				if $s.$p.is_empty() && !$s.empty_properties.contains($k) {
+					$s.$p = $g.$p.clone();
+					if $g.empty_properties.contains($k) { $s.empty_properties.insert(String::from($k)); }
+				}
+			};
+		}
+
+		inherit_property!(self, graph, vertices_coordinates, "vertices_coords");
+		inherit_property!(self, graph, vertices_vertices, "vertices_vertices");
+		inherit_property!(self, graph, vertices_edges, "vertices_edges");
+		inherit_property!(self, graph, vertices_faces, "vertices_faces");
+		inherit_property!(self, graph, edges_vertices, "edges_vertices");
+		inherit_property!(self, graph, edges_faces, "edges_faces");
+		inherit_property!(self, graph, edges_assignment, "edges_assignment");
+		inherit_property!(self, graph, edges_fold_angle, "edges_foldAngle");
+		inherit_property!(self, graph, edges_length, "edges_length");
+		inherit_property!(self, graph, edge_orders, "edgeOrders");
+		inherit_property!(self, graph, faces_vertices, "faces_vertices");
+		inherit_property!(self, graph, faces_edges, "faces_edges");
+		inherit_property!(self, graph, faces_faces, "faces_faces");
+		inherit_property!(self, graph, face_orders, "faceOrders");
+	}
+
+	// Removes every property equal to the one of the parent graph, so it is inherited instead
+	pub fn deflate_properties(&mut self, graph: &Self) {
+		macro_rules! deflate_property {
+			($s: expr, $g: expr, $p: ident, $k: expr) => {
+				if $s.$p == $g.$p {
+					$s.$p.clear();
+					$s.empty_properties.remove($k);
+				}
 			};
 		}
 
-		inherit_property!(self, graph, vertices_coordinates);
-		inherit_property!(self, graph, vertices_vertices);
-		inherit_property!(self, graph, vertices_edges);
-		inherit_property!(self, graph, vertices_faces);
-		inherit_property!(self, graph, edges_vertices);
-		inherit_property!(self, graph, edges_faces);
-		inherit_property!(self, graph, edges_assignment);
-		inherit_property!(self, graph, edges_fold_angle);
-		inherit_property!(self, graph, edges_length);
-		inherit_property!(self, graph, edge_orders);
-		inherit_property!(self, graph, faces_vertices);
-		inherit_property!(self, graph, faces_edges);
-		inherit_property!(self, graph, faces_faces);
-		inherit_property!(self, graph, face_orders);
+		deflate_property!(self, graph, vertices_coordinates, "vertices_coords");
+		deflate_property!(self, graph, vertices_vertices, "vertices_vertices");
+		deflate_property!(self, graph, vertices_edges, "vertices_edges");
+		deflate_property!(self, graph, vertices_faces, "vertices_faces");
+		deflate_property!(self, graph, edges_vertices, "edges_vertices");
+		deflate_property!(self, graph, edges_faces, "edges_faces");
+		deflate_property!(self, graph, edges_assignment, "edges_assignment");
+		deflate_property!(self, graph, edges_fold_angle, "edges_foldAngle");
+		deflate_property!(self, graph, edges_length, "edges_length");
+		deflate_property!(self, graph, edge_orders, "edgeOrders");
+		deflate_property!(self, graph, faces_vertices, "faces_vertices");
+		deflate_property!(self, graph, faces_edges, "faces_edges");
+		deflate_property!(self, graph, faces_faces, "faces_faces");
+		deflate_property!(self, graph, face_orders, "faceOrders");
+	}
+
+	// Missing properties are taken from the parent, so a graph lacking one the parent has cannot inherit
+	pub fn can_inherit_from(&self, graph: &Self) -> bool {
+		macro_rules! can_inherit_property {
+			($s: expr, $g: expr, $p: ident, $k: expr) => {
+				!$s.$p.is_empty() || $g.$p.is_empty() || $s.empty_properties.contains($k)
+			};
+		}
+
+		return can_inherit_property!(self, graph, vertices_coordinates, "vertices_coords")
+			&& can_inherit_property!(self, graph, vertices_vertices, "vertices_vertices")
+			&& can_inherit_property!(self, graph, vertices_edges, "vertices_edges")
+			&& can_inherit_property!(self, graph, vertices_faces, "vertices_faces")
+			&& can_inherit_property!(self, graph, edges_vertices, "edges_vertices")
+			&& can_inherit_property!(self, graph, edges_faces, "edges_faces")
+			&& can_inherit_property!(self, graph, edges_assignment, "edges_assignment")
+			&& can_inherit_property!(self, graph, edges_fold_angle, "edges_foldAngle")
+			&& can_inherit_property!(self, graph, edges_length, "edges_length")
+			&& can_inherit_property!(self, graph, edge_orders, "edgeOrders")
+			&& can_inherit_property!(self, graph, faces_vertices, "faces_vertices")
+			&& can_inherit_property!(self, graph, faces_edges, "faces_edges")
+			&& can_inherit_property!(self, graph, faces_faces, "faces_faces")
+			&& can_inherit_property!(self, graph, face_orders, "faceOrders");
+	}
+
+	pub fn insert_crease(&mut self, line: &Line, assignment: EdgeAssignment) -> Vec<usize> {
+		return arrangement::insert_line(self, line, assignment);
+	}
+
+	pub fn insert_segment(&mut self, a: &Point, b: &Point, assignment: EdgeAssignment) -> Vec<usize> {
+		return arrangement::insert_segment(self, a, b, assignment);
+	}
+
+	pub fn planarize(&mut self) {
+		arrangement::planarize(self);
+	}
+
+	pub fn diff(&self, graph: &Self) -> GraphDiff {
+		return diff::diff(self, graph);
+	}
+
+	// Merges the changes of ours and theirs, self being their common base
+	pub fn merge(&self, ours: &Self, theirs: &Self) -> GraphMerge {
+		return merge::merge(self, ours, theirs);
+	}
+
+	pub fn spatial_index(&self) -> SpatialIndex {
+		return SpatialIndex::new(self);
+	}
+
+	pub fn snap(&self, index: &SpatialIndex, cursor: &Point, options: &SnapOptions) -> Vec<SnapCandidate> {
+		return snap::snap(self, index, cursor, options);
 	}
 
 	pub fn validate(&self) -> Result<(), Error> {
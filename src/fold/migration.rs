@@ -0,0 +1,153 @@
+use serde_json::{Map, Value};
+
+use super::fold::Fold;
+use super::graph::FaceOrder;
+use crate::graph::make::make_faces_normals;
+
+pub const SPEC_VERSIONS: [f64; 3] = [1.0, 1.1, 1.2];
+pub const LATEST_VERSION: f64 = 1.2;
+
+// Keys renamed by version 1.1, as (legacy, current)
+const LEGACY_KEYS: [(&str, &str); 2] = [
+	("edges_foldAngles", "edges_foldAngle"),
+	("edges_lengths", "edges_length"),
+];
+
+#[derive(Clone, Copy, Debug)]
+pub enum MigrationChange {
+	UnknownVersion(f64),
+	SetVersion(f64, f64),
+	RenamedKey(usize, &'static str, &'static str),
+	// A legacy key whose property was already given or whose value could not be read
+	DroppedKey(usize, &'static str),
+	FlippedFaceOrders(usize, usize),
+	UnresolvedFaceOrders(usize),
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum MigrationError {
+	UnknownVersion(f64),
+	InvalidJson,
+}
+
+fn renamed_keys(version: f64) -> impl Iterator<Item = (&'static str, &'static str)> {
+	return LEGACY_KEYS.iter().map(move |&(legacy, current)| if version < 1.1 { (current, legacy) } else { (legacy, current) });
+}
+
+fn rename_keys(value: &mut Value, version: f64, changes: &mut Vec<MigrationChange>) {
+	let Value::Object(key_frame) = value else { return; };
+	rename_frame_keys(key_frame, 0, version, changes);
+	if let Some(Value::Array(frames)) = key_frame.get_mut("file_frames") {
+		for (index, frame) in frames.iter_mut().enumerate() {
+			if let Value::Object(frame) = frame { rename_frame_keys(frame, index + 1, version, changes); }
+		}
+	}
+}
+
+fn rename_frame_keys(frame: &mut Map<String, Value>, frame_index: usize, version: f64, changes: &mut Vec<MigrationChange>) {
+	for (from, to) in renamed_keys(version) {
+		if frame.contains_key(to) { continue; }
+		if let Some(value) = frame.remove(from) {
+			frame.insert(String::from(to), value);
+			changes.push(MigrationChange::RenamedKey(frame_index, from, to));
+		}
+	}
+}
+
+// Legacy keys can still end up in custom data when frames are built by hand
+fn migrate_custom_data(fold: &mut Fold, frame_index: usize, changes: &mut Vec<MigrationChange>) {
+	let frame = if frame_index == 0 { &mut fold.key_frame } else { &mut fold.frames[frame_index - 1] };
+	for (legacy, current) in LEGACY_KEYS {
+		let Some(value) = frame.custom_data.remove(legacy) else { continue; };
+		let field = match current {
+			"edges_foldAngle" => &mut frame.graph.edges_fold_angle,
+			_ => &mut frame.graph.edges_length,
+		};
+		if field.len() == 0 && let Ok(values) = serde_json::from_value(value) {
+			*field = values;
+			changes.push(MigrationChange::RenamedKey(frame_index, legacy, current));
+		} else {
+			changes.push(MigrationChange::DroppedKey(frame_index, legacy));
+		}
+	}
+}
+
+fn faces_normals(fold: &Fold, frame_index: usize) -> Option<Vec<[f64; 3]>> {
+	let frame = match fold.get_inherited_frame(frame_index) {
+		Ok(inherited_frame) => inherited_frame,
+		Err(frame) => frame.clone(),
+	};
+	let graph = &frame.graph;
+	let vertices_count = graph.vertices_coordinates.len();
+	if vertices_count == 0 { return None; }
+	if graph.faces_vertices.iter().flatten().any(|&vertex_index| vertex_index >= vertices_count) { return None; }
+	return Some(make_faces_normals(graph));
+}
+
+// Before version 1.2 the sign of faceOrders was taken along the normal of f, since then along the normal of g,
+// so the sign flips wherever the two faces point to opposite sides
+fn migrate_face_orders(fold: &mut Fold, frame_index: usize, changes: &mut Vec<MigrationChange>) {
+	if fold.get_frame(frame_index).unwrap().graph.face_orders.len() == 0 { return; }
+	let Some(normals) = faces_normals(fold, frame_index) else {
+		changes.push(MigrationChange::UnresolvedFaceOrders(frame_index));
+		return;
+	};
+
+	let frame = if frame_index == 0 { &mut fold.key_frame } else { &mut fold.frames[frame_index - 1] };
+	let mut flipped_count = 0;
+	for (f, g, order) in frame.graph.face_orders.iter_mut() {
+		let (Some(f_normal), Some(g_normal)) = (normals.get(*f), normals.get(*g)) else { continue; };
+		let dot = f_normal[0] * g_normal[0] + f_normal[1] * g_normal[1] + f_normal[2] * g_normal[2];
+		if dot >= 0.0 { continue; }
+		*order = match order {
+			FaceOrder::Above => FaceOrder::Below,
+			FaceOrder::Below => FaceOrder::Above,
+			FaceOrder::Unknown => continue,
+		};
+		flipped_count += 1;
+	}
+	if flipped_count > 0 { changes.push(MigrationChange::FlippedFaceOrders(frame_index, flipped_count)); }
+}
+
+pub fn migrate_fold(fold: &mut Fold, version: f64) -> Result<Vec<MigrationChange>, MigrationError> {
+	if !SPEC_VERSIONS.contains(&version) { return Err(MigrationError::UnknownVersion(version)); }
+	let mut changes: Vec<MigrationChange> = Vec::new();
+
+	// Files of an unknown version are taken to follow the latest one
+	let mut from = fold.version;
+	if !SPEC_VERSIONS.contains(&from) {
+		changes.push(MigrationChange::UnknownVersion(from));
+		from = LATEST_VERSION;
+	}
+
+	for frame_index in 0..(1 + fold.frames.len()) {
+		migrate_custom_data(fold, frame_index, &mut changes);
+		if (from < 1.2) != (version < 1.2) { migrate_face_orders(fold, frame_index, &mut changes); }
+	}
+
+	if fold.version != version {
+		changes.push(MigrationChange::SetVersion(fold.version, version));
+		fold.version = version;
+	}
+	return Ok(changes);
+}
+
+pub fn read_fold(json: &str) -> Result<(Fold, Vec<MigrationChange>), MigrationError> {
+	let mut value: Value = serde_json::from_str(json).map_err(|_| MigrationError::InvalidJson)?;
+	let mut changes: Vec<MigrationChange> = Vec::new();
+	rename_keys(&mut value, LATEST_VERSION, &mut changes);
+	let mut fold: Fold = serde_json::from_value(value).map_err(|_| MigrationError::InvalidJson)?;
+	changes.extend(migrate_fold(&mut fold, LATEST_VERSION)?);
+	return Ok((fold, changes));
+}
+
+// Only version 1.0 needs other keys, the others keep the field order of the structs
+pub fn write_fold(fold: &Fold, version: f64) -> Result<String, MigrationError> {
+	let mut fold = fold.clone();
+	migrate_fold(&mut fold, version)?;
+	if version >= 1.1 { return serde_json::to_string(&fold).map_err(|_| MigrationError::InvalidJson); }
+
+	let mut value = serde_json::to_value(&fold).map_err(|_| MigrationError::InvalidJson)?;
+	rename_keys(&mut value, version, &mut Vec::new());
+	return serde_json::to_string(&value).map_err(|_| MigrationError::InvalidJson);
+}
\ No newline at end of file
@@ -2,6 +2,8 @@ mod fold;
 mod frame;
 mod graph;
 mod validation;
+mod migration;
+mod tree;
 
 pub use fold::Fold;
 pub use fold::FoldClass;
@@ -16,4 +18,11 @@ pub use graph::EdgeAssignment;
 pub use graph::EdgeOrder;
 pub use graph::FaceOrder;
 
-pub use validation::Error;
\ No newline at end of file
+pub use validation::Error;
+
+pub use tree::FrameTree;
+
+pub use migration::MigrationChange;
+pub use migration::MigrationError;
+pub use migration::SPEC_VERSIONS;
+pub use migration::LATEST_VERSION;
\ No newline at end of file
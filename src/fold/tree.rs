@@ -0,0 +1,143 @@
+use std::collections::VecDeque;
+
+use super::fold::Fold;
+use super::frame::Frame;
+use super::validation::ParentError;
+
+// Parents and children of every frame, frame 0 being the key frame
+#[derive(Default, Clone, Debug)]
+pub struct FrameTree {
+	pub parents: Vec<Option<usize>>,
+	pub children: Vec<Vec<usize>>,
+	pub roots: Vec<usize>,
+}
+
+impl FrameTree {
+	pub fn new(fold: &Fold) -> Self {
+		return make_frame_tree(fold);
+	}
+
+	pub fn depth(&self, frame_index: usize) -> usize {
+		return frame_ancestors_in(&self.parents, frame_index).len();
+	}
+}
+
+fn frames_parents(fold: &Fold) -> Vec<Option<usize>> {
+	let frames_count = 1 + fold.frames.len();
+	return (0..frames_count).map(|frame_index| {
+		fold.get_frame(frame_index).unwrap().parent.filter(|&parent_index| parent_index < frames_count && parent_index != frame_index)
+	}).collect();
+}
+
+fn frame_ancestors_in(parents: &[Option<usize>], frame_index: usize) -> Vec<usize> {
+	let mut ancestors: Vec<usize> = Vec::new();
+	let mut current = frame_index;
+	while let Some(parent_index) = parents[current] {
+		if parent_index == frame_index || ancestors.contains(&parent_index) { break; }
+		ancestors.push(parent_index);
+		current = parent_index;
+	}
+	return ancestors;
+}
+
+pub fn make_frame_tree(fold: &Fold) -> FrameTree {
+	let parents = frames_parents(fold);
+	let mut children: Vec<Vec<usize>> = vec![Vec::new(); parents.len()];
+	let mut roots: Vec<usize> = Vec::new();
+	for (frame_index, parent) in parents.iter().enumerate() {
+		match parent {
+			Some(parent_index) => children[*parent_index].push(frame_index),
+			None => roots.push(frame_index),
+		}
+	}
+	return FrameTree { parents, children, roots };
+}
+
+pub fn frame_children(fold: &Fold, frame_index: usize) -> Vec<usize> {
+	return frames_parents(fold).iter().enumerate()
+		.filter(|(_, parent)| **parent == Some(frame_index))
+		.map(|(child_index, _)| child_index)
+		.collect();
+}
+
+// Nearest parent first, a cycle stops the walk
+pub fn frame_ancestors(fold: &Fold, frame_index: usize) -> Vec<usize> {
+	if fold.get_frame(frame_index).is_none() { return Vec::new(); }
+	return frame_ancestors_in(&frames_parents(fold), frame_index);
+}
+
+pub fn frame_roots(fold: &Fold) -> Vec<usize> {
+	return make_frame_tree(fold).roots;
+}
+
+// Every frame comes after its parent, frames of a cycle are reported like validate_frame_parents does
+pub fn frames_topological_order(fold: &Fold) -> Result<Vec<usize>, ParentError> {
+	let frames_count = 1 + fold.frames.len();
+	// The tree leaves out self parents, so they are caught here
+	for frame_index in 0..frames_count {
+		let Some(parent_index) = fold.get_frame(frame_index).unwrap().parent else { continue; };
+		if parent_index >= frames_count { return Err(ParentError::MissingParent(frame_index, parent_index)); }
+		if parent_index == frame_index { return Err(ParentError::InfiniteParents(frame_index)); }
+	}
+
+	let tree = make_frame_tree(fold);
+	let mut order: Vec<usize> = Vec::with_capacity(frames_count);
+	let mut queue: VecDeque<usize> = tree.roots.iter().copied().collect();
+	while let Some(frame_index) = queue.pop_front() {
+		order.push(frame_index);
+		queue.extend(tree.children[frame_index].iter().copied());
+	}
+
+	if order.len() < frames_count {
+		let frame_index = (0..frames_count).find(|frame_index| !order.contains(frame_index)).unwrap();
+		return Err(ParentError::InfiniteParents(frame_index));
+	}
+	return Ok(order);
+}
+
+fn remap_parents(fold: &mut Fold, remap: impl Fn(usize) -> Option<usize>) {
+	fold.key_frame.parent = fold.key_frame.parent.and_then(&remap);
+	for frame in fold.frames.iter_mut() {
+		frame.parent = frame.parent.and_then(&remap);
+	}
+}
+
+// The parent of the new frame is given with the indices from before the insertion
+pub fn insert_frame(fold: &mut Fold, frame_index: usize, frame: Frame) {
+	let frame_index = frame_index.clamp(1, 1 + fold.frames.len());
+	fold.frames.insert(frame_index - 1, frame);
+	remap_parents(fold, |index| Some(if index >= frame_index { index + 1 } else { index }));
+}
+
+// Children move to the parent of the removed frame and keep what they inherited from it
+pub fn remove_frame(fold: &mut Fold, frame_index: usize) -> Option<Frame> {
+	if frame_index == 0 || frame_index > fold.frames.len() { return None; }
+	let removed_frame = fold.frames.remove(frame_index - 1);
+	let removed_parent = removed_frame.parent.filter(|&parent_index| parent_index != frame_index);
+
+	let shift = |index: usize| if index > frame_index { index - 1 } else { index };
+	for frame in std::iter::once(&mut fold.key_frame).chain(fold.frames.iter_mut()) {
+		if frame.parent != Some(frame_index) { continue; }
+		if frame.inherit {
+			frame.inherit_properties(&removed_frame);
+			if !removed_frame.inherit { frame.inherit = false; }
+		}
+		frame.parent = removed_parent;
+	}
+	remap_parents(fold, |index| Some(shift(index)));
+	return Some(removed_frame);
+}
+
+pub fn move_frame(fold: &mut Fold, from_index: usize, to_index: usize) {
+	let frames_count = fold.frames.len();
+	if from_index == 0 || to_index == 0 || from_index > frames_count || to_index > frames_count { return; }
+
+	let frame = fold.frames.remove(from_index - 1);
+	fold.frames.insert(to_index - 1, frame);
+	remap_parents(fold, |index| {
+		if index == from_index { return Some(to_index); }
+		if from_index < to_index && index > from_index && index <= to_index { return Some(index - 1); }
+		if to_index < from_index && index >= to_index && index < from_index { return Some(index + 1); }
+		return Some(index);
+	});
+}
\ No newline at end of file
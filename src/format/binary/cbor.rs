@@ -0,0 +1,16 @@
+use crate::fold::Fold;
+use super::error::BinaryError;
+use super::packed::with_packed_coordinates;
+
+pub fn export_cbor(fold: &Fold, packed: bool) -> Result<Vec<u8>, BinaryError> {
+	let mut bytes: Vec<u8> = Vec::new();
+	with_packed_coordinates(packed, || ciborium::into_writer(fold, &mut bytes)).map_err(|_| BinaryError::InvalidEncoding)?;
+	return Ok(bytes);
+}
+
+pub fn import_cbor(bytes: &[u8]) -> Result<Fold, BinaryError> {
+	return ciborium::from_reader(bytes).map_err(|error| match error {
+		ciborium::de::Error::Semantic(..) => BinaryError::InvalidFold,
+		_ => BinaryError::InvalidEncoding,
+	});
+}
\ No newline at end of file
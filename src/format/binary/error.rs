@@ -0,0 +1,5 @@
+#[derive(Clone, Copy, Debug)]
+pub enum BinaryError {
+	InvalidEncoding,
+	InvalidFold,
+}
\ No newline at end of file
@@ -0,0 +1,25 @@
+mod error;
+mod packed;
+
+#[cfg(feature = "cbor")]
+mod cbor;
+
+#[cfg(feature = "msgpack")]
+mod msgpack;
+
+pub use error::BinaryError;
+
+pub use packed::with_packed_coordinates;
+pub use packed::serialize_coordinates;
+pub use packed::deserialize_coordinates;
+pub use packed::deserialize_optional_coordinates;
+
+#[cfg(feature = "cbor")]
+pub use cbor::import_cbor;
+#[cfg(feature = "cbor")]
+pub use cbor::export_cbor;
+
+#[cfg(feature = "msgpack")]
+pub use msgpack::import_msgpack;
+#[cfg(feature = "msgpack")]
+pub use msgpack::export_msgpack;
\ No newline at end of file
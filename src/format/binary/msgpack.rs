@@ -0,0 +1,15 @@
+use crate::fold::Fold;
+use super::error::BinaryError;
+use super::packed::with_packed_coordinates;
+
+// Structs are written as maps, like the FOLD objects they stand for
+pub fn export_msgpack(fold: &Fold, packed: bool) -> Result<Vec<u8>, BinaryError> {
+	return with_packed_coordinates(packed, || rmp_serde::to_vec_named(fold)).map_err(|_| BinaryError::InvalidEncoding);
+}
+
+pub fn import_msgpack(bytes: &[u8]) -> Result<Fold, BinaryError> {
+	return rmp_serde::from_slice(bytes).map_err(|error| match error {
+		rmp_serde::decode::Error::Syntax(_) => BinaryError::InvalidFold,
+		_ => BinaryError::InvalidEncoding,
+	});
+}
\ No newline at end of file
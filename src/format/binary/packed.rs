@@ -0,0 +1,132 @@
+use std::cell::Cell;
+
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+use serde::ser::SerializeMap;
+use serde::de::{self, Visitor, SeqAccess, MapAccess};
+
+use crate::real::Real;
+
+thread_local! {
+	static PACK_COORDINATES: Cell<bool> = const { Cell::new(false) };
+}
+
+// Serde has no way to hand options down to a field, so binary exports turn packing on around their call
+pub fn with_packed_coordinates<T>(packed: bool, f: impl FnOnce() -> T) -> T {
+	let previous = PACK_COORDINATES.replace(packed);
+	let result = f();
+	PACK_COORDINATES.set(previous);
+	return result;
+}
+
+struct Bytes<'a>(&'a [u8]);
+
+impl Serialize for Bytes<'_> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		return serializer.serialize_bytes(self.0);
+	}
+}
+
+// Packed coordinates are a byte string of little endian f64 values, one vertex after the other,
+// used only when every value is exactly a f64 so that packing never loses precision
+fn packed_bytes(coordinates: &[Vec<Real>]) -> Option<(usize, Vec<u8>)> {
+	let dimension = coordinates.first()?.len();
+	let mut bytes: Vec<u8> = Vec::with_capacity(coordinates.len() * dimension * 8);
+	for vertex_coordinates in coordinates {
+		if vertex_coordinates.len() != dimension { return None; }
+		for coordinate in vertex_coordinates {
+			let number = coordinate.to_f64();
+			if Real::from_f64(number) != *coordinate { return None; }
+			bytes.extend_from_slice(&number.to_le_bytes());
+		}
+	}
+	return Some((dimension, bytes));
+}
+
+// Text formats always get plain arrays
+pub fn serialize_coordinates<S: Serializer>(coordinates: &[Vec<Real>], serializer: S) -> Result<S::Ok, S::Error> {
+	if !serializer.is_human_readable() && PACK_COORDINATES.get() && let Some((dimension, bytes)) = packed_bytes(coordinates) {
+		let mut map = serializer.serialize_map(Some(2))?;
+		map.serialize_entry("dimension", &dimension)?;
+		map.serialize_entry("float64", &Bytes(&bytes))?;
+		return map.end();
+	}
+	return coordinates.serialize(serializer);
+}
+
+// Byte strings, or arrays of bytes for formats without them
+struct PackedBytes(Vec<u8>);
+
+struct PackedBytesVisitor;
+
+impl<'de> Visitor<'de> for PackedBytesVisitor {
+	type Value = PackedBytes;
+
+	fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+		return formatter.write_str("a byte string");
+	}
+
+	fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<PackedBytes, E> {
+		return Ok(PackedBytes(value.to_vec()));
+	}
+
+	fn visit_byte_buf<E: de::Error>(self, value: Vec<u8>) -> Result<PackedBytes, E> {
+		return Ok(PackedBytes(value));
+	}
+
+	fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<PackedBytes, A::Error> {
+		let mut bytes: Vec<u8> = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+		while let Some(byte) = seq.next_element::<u8>()? {
+			bytes.push(byte);
+		}
+		return Ok(PackedBytes(bytes));
+	}
+}
+
+impl<'de> Deserialize<'de> for PackedBytes {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		return deserializer.deserialize_bytes(PackedBytesVisitor);
+	}
+}
+
+struct CoordinatesVisitor;
+
+impl<'de> Visitor<'de> for CoordinatesVisitor {
+	type Value = Vec<Vec<Real>>;
+
+	fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+		return formatter.write_str("an array of coordinates or packed coordinates");
+	}
+
+	fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<Vec<Real>>, A::Error> {
+		let mut coordinates: Vec<Vec<Real>> = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+		while let Some(vertex_coordinates) = seq.next_element::<Vec<Real>>()? {
+			coordinates.push(vertex_coordinates);
+		}
+		return Ok(coordinates);
+	}
+
+	fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Vec<Vec<Real>>, A::Error> {
+		let (mut dimension, mut bytes): (Option<usize>, Option<Vec<u8>>) = (None, None);
+		while let Some(key) = map.next_key::<String>()? {
+			match key.as_str() {
+				"dimension" => dimension = Some(map.next_value()?),
+				"float64" => bytes = Some(map.next_value::<PackedBytes>()?.0),
+				_ => { map.next_value::<de::IgnoredAny>()?; },
+			}
+		}
+		let dimension = dimension.ok_or_else(|| de::Error::missing_field("dimension"))?;
+		let bytes = bytes.ok_or_else(|| de::Error::missing_field("float64"))?;
+		if dimension == 0 || !bytes.len().is_multiple_of(8 * dimension) { return Err(de::Error::invalid_length(bytes.len(), &self)); }
+		return Ok(bytes.chunks_exact(8 * dimension).map(|vertex_bytes| {
+			vertex_bytes.chunks_exact(8).map(|chunk| Real::from_f64(f64::from_le_bytes(chunk.try_into().unwrap()))).collect()
+		}).collect());
+	}
+}
+
+pub fn deserialize_coordinates<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Vec<Real>>, D::Error> {
+	return deserializer.deserialize_any(CoordinatesVisitor);
+}
+
+pub fn deserialize_optional_coordinates<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Vec<Vec<Real>>>, D::Error> {
+	return deserialize_coordinates(deserializer).map(Some);
+}
\ No newline at end of file
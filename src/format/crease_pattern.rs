@@ -0,0 +1,38 @@
+use crate::fold::{Fold, Graph, EdgeAssignment};
+use crate::graph::arrangement::{planarize, assignment_fold_angle};
+use crate::real::Real;
+
+pub type Segment = ([f64; 2], [f64; 2], EdgeAssignment);
+
+pub fn make_crease_pattern(segments: &[Segment]) -> Fold {
+	let mut graph = Graph::new();
+	for (a, b, assignment) in segments {
+		let vertex_index = graph.vertices_coordinates.len();
+		for point in [a, b] {
+			graph.vertices_coordinates.push(vec![Real::from_f64(point[0]), Real::from_f64(point[1])]);
+		}
+		graph.edges_vertices.push(vec![vertex_index, vertex_index + 1]);
+		graph.edges_assignment.push(*assignment);
+		graph.edges_fold_angle.push(assignment_fold_angle(*assignment));
+	}
+	planarize(&mut graph);
+
+	let mut fold = Fold::new();
+	fold.classes.push(String::from("singleModel"));
+	fold.key_frame.classes.push(String::from("creasePattern"));
+	fold.key_frame.attributes.push(String::from("2D"));
+	fold.key_frame.graph = graph;
+	return fold;
+}
+
+pub fn crease_pattern_segments(graph: &Graph) -> Vec<Segment> {
+	let position = |vertex_index: usize| -> [f64; 2] {
+		let coordinates = &graph.vertices_coordinates[vertex_index];
+		let coordinate = |index: usize| coordinates.get(index).map(|value| value.to_f64()).unwrap_or(0.0);
+		return [coordinate(0), coordinate(1)];
+	};
+	return graph.edges_vertices.iter().enumerate().map(|(edge_index, edge_vertices)| {
+		let assignment = graph.edges_assignment.get(edge_index).copied().unwrap_or(EdgeAssignment::Unknown);
+		(position(edge_vertices[0]), position(edge_vertices[1]), assignment)
+	}).collect();
+}
\ No newline at end of file
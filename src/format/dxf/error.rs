@@ -0,0 +1,6 @@
+#[derive(Clone, Copy, Debug)]
+pub enum DxfError {
+	InvalidGroupCode,
+	InvalidNumber,
+	MissingValue,
+}
\ No newline at end of file
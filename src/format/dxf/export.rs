@@ -0,0 +1,64 @@
+use std::fmt::Write;
+
+use crate::fold::Frame;
+use crate::format::crease_pattern::crease_pattern_segments;
+use super::layer::{LAYERS, assignment_layer, unit_insunits};
+
+fn group(dxf: &mut String, code: i64, value: impl std::fmt::Display) {
+	let _ = write!(dxf, "{}\n{}\n", code, value);
+}
+
+// Written as R12 so that no entity handles are needed, older cutter software reads it too,
+// $INSUNITS only exists since R2000 but R12 readers skip header variables they do not know
+pub fn export_dxf(frame: &Frame) -> String {
+	let mut dxf = String::new();
+	group(&mut dxf, 0, "SECTION");
+	group(&mut dxf, 2, "HEADER");
+	group(&mut dxf, 9, "$ACADVER");
+	group(&mut dxf, 1, "AC1009");
+	group(&mut dxf, 9, "$INSUNITS");
+	group(&mut dxf, 70, unit_insunits(&frame.unit));
+	group(&mut dxf, 0, "ENDSEC");
+
+	group(&mut dxf, 0, "SECTION");
+	group(&mut dxf, 2, "TABLES");
+	group(&mut dxf, 0, "TABLE");
+	group(&mut dxf, 2, "LTYPE");
+	group(&mut dxf, 70, 1);
+	group(&mut dxf, 0, "LTYPE");
+	group(&mut dxf, 2, "CONTINUOUS");
+	group(&mut dxf, 70, 0);
+	group(&mut dxf, 3, "Solid line");
+	group(&mut dxf, 72, 65);
+	group(&mut dxf, 73, 0);
+	group(&mut dxf, 40, 0.0);
+	group(&mut dxf, 0, "ENDTAB");
+	group(&mut dxf, 0, "TABLE");
+	group(&mut dxf, 2, "LAYER");
+	group(&mut dxf, 70, LAYERS.len());
+	for (name, _, color) in LAYERS {
+		group(&mut dxf, 0, "LAYER");
+		group(&mut dxf, 2, name);
+		group(&mut dxf, 70, 0);
+		group(&mut dxf, 62, color);
+		group(&mut dxf, 6, "CONTINUOUS");
+	}
+	group(&mut dxf, 0, "ENDTAB");
+	group(&mut dxf, 0, "ENDSEC");
+
+	group(&mut dxf, 0, "SECTION");
+	group(&mut dxf, 2, "ENTITIES");
+	for (a, b, assignment) in crease_pattern_segments(&frame.graph) {
+		group(&mut dxf, 0, "LINE");
+		group(&mut dxf, 8, assignment_layer(assignment));
+		group(&mut dxf, 10, a[0]);
+		group(&mut dxf, 20, a[1]);
+		group(&mut dxf, 30, 0.0);
+		group(&mut dxf, 11, b[0]);
+		group(&mut dxf, 21, b[1]);
+		group(&mut dxf, 31, 0.0);
+	}
+	group(&mut dxf, 0, "ENDSEC");
+	group(&mut dxf, 0, "EOF");
+	return dxf;
+}
\ No newline at end of file
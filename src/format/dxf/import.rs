@@ -0,0 +1,90 @@
+use crate::fold::Fold;
+use crate::format::crease_pattern::{Segment, make_crease_pattern};
+use super::error::DxfError;
+use super::layer::{layer_assignment, insunits_unit};
+
+type Group<'a> = (i64, &'a str);
+
+fn parse_groups(dxf: &str) -> Result<Vec<Group<'_>>, DxfError> {
+	let mut groups: Vec<Group> = Vec::new();
+	let mut lines = dxf.lines();
+	while let Some(line) = lines.next() {
+		if line.trim().len() == 0 { continue; }
+		let code = line.trim().parse::<i64>().map_err(|_| DxfError::InvalidGroupCode)?;
+		let value = lines.next().ok_or(DxfError::MissingValue)?;
+		groups.push((code, value.trim()));
+	}
+	return Ok(groups);
+}
+
+fn parse_number(value: &str) -> Result<f64, DxfError> {
+	return value.parse::<f64>().map_err(|_| DxfError::InvalidNumber);
+}
+
+fn entity_layer<'a>(entity: &[Group<'a>]) -> &'a str {
+	return entity.iter().find(|(code, _)| *code == 8).map(|(_, value)| *value).unwrap_or("0");
+}
+
+fn parse_line(entity: &[Group]) -> Result<Segment, DxfError> {
+	let mut values = [0.0; 4];
+	for (code, value) in entity {
+		let index = match code { 10 => 0, 20 => 1, 11 => 2, 21 => 3, _ => continue };
+		values[index] = parse_number(value)?;
+	}
+	return Ok(([values[0], values[1]], [values[2], values[3]], layer_assignment(entity_layer(entity))));
+}
+
+fn parse_lwpolyline(entity: &[Group], segments: &mut Vec<Segment>) -> Result<(), DxfError> {
+	let assignment = layer_assignment(entity_layer(entity));
+	let mut points: Vec<[f64; 2]> = Vec::new();
+	let mut is_closed = false;
+	for (code, value) in entity {
+		match code {
+			10 => points.push([parse_number(value)?, 0.0]),
+			20 => if let Some(point) = points.last_mut() { point[1] = parse_number(value)?; },
+			70 => is_closed = value.parse::<i64>().map_err(|_| DxfError::InvalidNumber)? & 1 == 1,
+			_ => {},
+		}
+	}
+
+	// Bulges are ignored, arcs between vertices are read as straight segments
+	for pair in points.windows(2) {
+		segments.push((pair[0], pair[1], assignment));
+	}
+	if is_closed && points.len() > 2 {
+		segments.push((points[points.len() - 1], points[0], assignment));
+	}
+	return Ok(());
+}
+
+pub fn import_dxf(dxf: &str) -> Result<Fold, DxfError> {
+	let groups = parse_groups(dxf)?;
+	let mut segments: Vec<Segment> = Vec::new();
+	let mut unit = String::new();
+
+	// Records start at every group with code 0 and hold the groups up to the next one
+	let mut section = "";
+	let starts: Vec<usize> = (0..groups.len()).filter(|&index| groups[index].0 == 0).collect();
+	for (start_index, &start) in starts.iter().enumerate() {
+		let end = starts.get(start_index + 1).copied().unwrap_or(groups.len());
+		let record = &groups[start + 1..end];
+		match groups[start].1 {
+			"SECTION" => {
+				section = record.iter().find(|(code, _)| *code == 2).map(|(_, value)| *value).unwrap_or("");
+				if section != "HEADER" { continue; }
+				let position = record.iter().position(|group| *group == (9, "$INSUNITS"));
+				if let Some((_, value)) = position.and_then(|position| record.get(position + 1)) {
+					unit = insunits_unit(value.parse::<i64>().map_err(|_| DxfError::InvalidNumber)?);
+				}
+			},
+			"ENDSEC" => section = "",
+			"LINE" if section == "ENTITIES" => segments.push(parse_line(record)?),
+			"LWPOLYLINE" if section == "ENTITIES" => parse_lwpolyline(record, &mut segments)?,
+			_ => {},
+		}
+	}
+
+	let mut fold = make_crease_pattern(&segments);
+	fold.key_frame.unit = unit;
+	return Ok(fold);
+}
\ No newline at end of file
@@ -0,0 +1,41 @@
+use crate::fold::EdgeAssignment;
+
+pub const LAYERS: [(&str, EdgeAssignment, i64); 7] = [
+	("BOUNDARY", EdgeAssignment::Boundary, 7),
+	("MOUNTAIN", EdgeAssignment::Mountain, 1),
+	("VALLEY", EdgeAssignment::Valley, 5),
+	("FLAT", EdgeAssignment::Flat, 8),
+	("UNASSIGNED", EdgeAssignment::Unknown, 9),
+	("CUT", EdgeAssignment::Cut, 3),
+	("JOIN", EdgeAssignment::Join, 6),
+];
+
+pub fn assignment_layer(assignment: EdgeAssignment) -> &'static str {
+	return LAYERS.iter().find(|(_, layer_assignment, _)| *layer_assignment == assignment).unwrap().0;
+}
+
+// Layers that are not ours are read as unassigned creases
+pub fn layer_assignment(layer: &str) -> EdgeAssignment {
+	return LAYERS.iter()
+		.find(|(name, _, _)| name.eq_ignore_ascii_case(layer))
+		.map(|(_, assignment, _)| *assignment)
+		.unwrap_or(EdgeAssignment::Unknown);
+}
+
+const UNITS: [(&str, i64); 6] = [
+	("in", 1),
+	("mm", 4),
+	("cm", 5),
+	("m", 6),
+	("nm", 12),
+	("um", 13),
+];
+
+// Units without an $INSUNITS code, points among them, are written as unitless
+pub fn unit_insunits(unit: &str) -> i64 {
+	return UNITS.iter().find(|(name, _)| *name == unit).map(|(_, insunits)| *insunits).unwrap_or(0);
+}
+
+pub fn insunits_unit(insunits: i64) -> String {
+	return UNITS.iter().find(|(_, code)| *code == insunits).map(|(name, _)| name.to_string()).unwrap_or_default();
+}
\ No newline at end of file
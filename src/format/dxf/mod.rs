@@ -0,0 +1,12 @@
+mod error;
+mod layer;
+mod import;
+mod export;
+
+pub use error::DxfError;
+
+pub use layer::assignment_layer;
+pub use layer::layer_assignment;
+
+pub use import::import_dxf;
+pub use export::export_dxf;
\ No newline at end of file
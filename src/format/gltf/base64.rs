@@ -0,0 +1,18 @@
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+pub fn encode_base64(bytes: &[u8]) -> String {
+	let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+	for chunk in bytes.chunks(3) {
+		let value = (chunk[0] as u32) << 16
+			| (chunk.get(1).copied().unwrap_or(0) as u32) << 8
+			| chunk.get(2).copied().unwrap_or(0) as u32;
+		for index in 0..4 {
+			if index <= chunk.len() {
+				encoded.push(ALPHABET[(value >> (18 - 6 * index) & 63) as usize] as char);
+			} else {
+				encoded.push('=');
+			}
+		}
+	}
+	return encoded;
+}
\ No newline at end of file
@@ -0,0 +1,70 @@
+use serde_json::{Value, json};
+
+const FLOAT: u32 = 5126;
+const UNSIGNED_INT: u32 = 5125;
+pub const ARRAY_BUFFER: u32 = 34962;
+pub const ELEMENT_ARRAY_BUFFER: u32 = 34963;
+
+#[derive(Default, Clone, Debug)]
+pub struct BufferBuilder {
+	pub bytes: Vec<u8>,
+	pub buffer_views: Vec<Value>,
+	pub accessors: Vec<Value>,
+}
+
+impl BufferBuilder {
+	pub fn new() -> Self {
+		return Self { ..Default::default() }
+	}
+
+	fn push_view(&mut self, bytes: &[u8], target: Option<u32>) -> usize {
+		let offset = self.bytes.len();
+		self.bytes.extend_from_slice(bytes);
+		while !self.bytes.len().is_multiple_of(4) { self.bytes.push(0); }
+
+		let mut buffer_view = json!({ "buffer": 0, "byteOffset": offset, "byteLength": bytes.len() });
+		if let Some(target) = target { buffer_view["target"] = json!(target); }
+		self.buffer_views.push(buffer_view);
+		return self.buffer_views.len() - 1;
+	}
+
+	// Components are 1 for SCALAR and 3 for VEC3, bounds are required for positions and animation inputs
+	pub fn push_floats(&mut self, values: &[f32], components: usize, target: Option<u32>, bounds: bool) -> usize {
+		let bytes: Vec<u8> = values.iter().flat_map(|value| value.to_le_bytes()).collect();
+		let buffer_view = self.push_view(&bytes, target);
+		let accessor_type = if components == 1 { "SCALAR" } else { "VEC3" };
+
+		let mut accessor = json!({
+			"bufferView": buffer_view,
+			"componentType": FLOAT,
+			"count": values.len() / components,
+			"type": accessor_type,
+		});
+		if bounds {
+			let mut minimum = vec![f32::INFINITY; components];
+			let mut maximum = vec![f32::NEG_INFINITY; components];
+			for element in values.chunks_exact(components) {
+				for (axis, value) in element.iter().enumerate() {
+					minimum[axis] = minimum[axis].min(*value);
+					maximum[axis] = maximum[axis].max(*value);
+				}
+			}
+			accessor["min"] = json!(minimum);
+			accessor["max"] = json!(maximum);
+		}
+		self.accessors.push(accessor);
+		return self.accessors.len() - 1;
+	}
+
+	pub fn push_indices(&mut self, indices: &[u32]) -> usize {
+		let bytes: Vec<u8> = indices.iter().flat_map(|index| index.to_le_bytes()).collect();
+		let buffer_view = self.push_view(&bytes, Some(ELEMENT_ARRAY_BUFFER));
+		self.accessors.push(json!({
+			"bufferView": buffer_view,
+			"componentType": UNSIGNED_INT,
+			"count": indices.len(),
+			"type": "SCALAR",
+		}));
+		return self.accessors.len() - 1;
+	}
+}
\ No newline at end of file
@@ -0,0 +1,5 @@
+#[derive(Clone, Copy, Debug)]
+pub enum GltfError {
+	MissingCoordinates,
+	MissingFaces,
+}
\ No newline at end of file
@@ -0,0 +1,202 @@
+use serde_json::{Value, json};
+
+use crate::fold::{Fold, Frame, Graph};
+use crate::graph::make::{make_faces_triangles, make_face_normal};
+use super::error::GltfError;
+use super::buffer::{BufferBuilder, ARRAY_BUFFER};
+use super::base64::encode_base64;
+
+#[derive(Clone, Debug)]
+pub struct GltfOptions {
+	pub frame_duration: f64,
+	pub front_color: [f64; 4],
+	pub back_color: [f64; 4],
+}
+
+impl Default for GltfOptions {
+	fn default() -> Self {
+		return Self {
+			frame_duration: 1.0,
+			front_color: [1.0, 1.0, 1.0, 1.0],
+			back_color: [0.6, 0.7, 0.9, 1.0],
+		}
+	}
+}
+
+impl GltfOptions {
+	pub fn new() -> Self {
+		return Self { ..Default::default() }
+	}
+}
+
+// Every face gets its own copies of its vertices, so the faces are flat shaded
+struct FaceCorners {
+	corners: Vec<(usize, usize)>,
+	front_indices: Vec<u32>,
+	back_indices: Vec<u32>,
+}
+
+fn make_face_corners(graph: &Graph) -> FaceCorners {
+	let mut face_corners = FaceCorners { corners: Vec::new(), front_indices: Vec::new(), back_indices: Vec::new() };
+	for (face_index, face_triangles) in make_faces_triangles(graph).iter().enumerate() {
+		let face_vertices = &graph.faces_vertices[face_index];
+		let offset = face_corners.corners.len();
+		face_corners.corners.extend(face_vertices.iter().map(|&vertex_index| (face_index, vertex_index)));
+
+		for triangle in face_triangles {
+			let corner = |vertex_index: usize| (offset + face_vertices.iter().position(|&v| v == vertex_index).unwrap()) as u32;
+			let [a, b, c] = triangle.map(corner);
+			face_corners.front_indices.extend([a, b, c]);
+			face_corners.back_indices.extend([a, c, b]);
+		}
+	}
+	return face_corners;
+}
+
+fn vertex_position(graph: &Graph, vertex_index: usize) -> [f32; 3] {
+	let coordinates = &graph.vertices_coordinates[vertex_index];
+	let coordinate = |index: usize| coordinates.get(index).map(|value| value.to_f64() as f32).unwrap_or(0.0);
+	return [coordinate(0), coordinate(1), coordinate(2)];
+}
+
+fn corners_positions(graph: &Graph, face_corners: &FaceCorners) -> Vec<f32> {
+	return face_corners.corners.iter().flat_map(|&(_, vertex_index)| vertex_position(graph, vertex_index)).collect();
+}
+
+fn corners_normals(graph: &Graph, face_corners: &FaceCorners) -> Vec<f32> {
+	let faces_normals: Vec<[f32; 3]> = graph.faces_vertices.iter()
+		.map(|face_vertices| make_face_normal(graph, face_vertices).map(|value| value as f32))
+		.collect();
+	return face_corners.corners.iter().flat_map(|&(face_index, _)| faces_normals[face_index]).collect();
+}
+
+fn has_same_topology(frame: &Frame, key_frame: &Frame) -> bool {
+	return frame.graph.vertices_coordinates.len() == key_frame.graph.vertices_coordinates.len()
+		&& frame.graph.faces_vertices == key_frame.graph.faces_vertices;
+}
+
+fn material(name: &str, color: [f64; 4]) -> Value {
+	return json!({
+		"name": name,
+		"pbrMetallicRoughness": { "baseColorFactor": color, "metallicFactor": 0.0, "roughnessFactor": 0.9 },
+	});
+}
+
+fn difference(values: &[f32], base: &[f32]) -> Vec<f32> {
+	return values.iter().zip(base).map(|(value, base)| value - base).collect();
+}
+
+pub fn make_gltf(fold: &Fold, options: &GltfOptions) -> Result<(Value, Vec<u8>), GltfError> {
+	let key_frame = &fold.key_frame;
+	let graph = &key_frame.graph;
+	if graph.vertices_coordinates.len() == 0 { return Err(GltfError::MissingCoordinates); }
+	if graph.faces_vertices.len() == 0 { return Err(GltfError::MissingFaces); }
+
+	let face_corners = make_face_corners(graph);
+	let positions = corners_positions(graph, &face_corners);
+	let normals = corners_normals(graph, &face_corners);
+	let back_normals: Vec<f32> = normals.iter().map(|value| -value).collect();
+
+	let mut buffer = BufferBuilder::new();
+	let position_accessor = buffer.push_floats(&positions, 3, Some(ARRAY_BUFFER), true);
+	let normal_accessor = buffer.push_floats(&normals, 3, Some(ARRAY_BUFFER), false);
+	let back_normal_accessor = buffer.push_floats(&back_normals, 3, Some(ARRAY_BUFFER), false);
+	let front_indices_accessor = buffer.push_indices(&face_corners.front_indices);
+	let back_indices_accessor = buffer.push_indices(&face_corners.back_indices);
+
+	// Frames sharing the topology of the key frame become morph targets
+	let mut front_targets: Vec<Value> = Vec::new();
+	let mut back_targets: Vec<Value> = Vec::new();
+	for frame_index in 1..(1 + fold.frames.len()) {
+		let frame = match fold.get_inherited_frame(frame_index) {
+			Ok(inherited_frame) => inherited_frame,
+			Err(frame) => frame.clone(),
+		};
+		if !has_same_topology(&frame, key_frame) { continue; }
+
+		let frame_positions = corners_positions(&frame.graph, &face_corners);
+		let frame_normals = corners_normals(&frame.graph, &face_corners);
+		let frame_back_normals: Vec<f32> = frame_normals.iter().map(|value| -value).collect();
+		let position_target = buffer.push_floats(&difference(&frame_positions, &positions), 3, Some(ARRAY_BUFFER), true);
+		let normal_target = buffer.push_floats(&difference(&frame_normals, &normals), 3, Some(ARRAY_BUFFER), false);
+		let back_normal_target = buffer.push_floats(&difference(&frame_back_normals, &back_normals), 3, Some(ARRAY_BUFFER), false);
+		front_targets.push(json!({ "POSITION": position_target, "NORMAL": normal_target }));
+		back_targets.push(json!({ "POSITION": position_target, "NORMAL": back_normal_target }));
+	}
+
+	let mut front_primitive = json!({
+		"attributes": { "POSITION": position_accessor, "NORMAL": normal_accessor },
+		"indices": front_indices_accessor,
+		"material": 0,
+	});
+	let mut back_primitive = json!({
+		"attributes": { "POSITION": position_accessor, "NORMAL": back_normal_accessor },
+		"indices": back_indices_accessor,
+		"material": 1,
+	});
+	let mut mesh = json!({ "name": "fold" });
+	if key_frame.title.len() > 0 { mesh["name"] = json!(key_frame.title); }
+
+	let targets_count = front_targets.len();
+	let mut animations: Vec<Value> = Vec::new();
+	if targets_count > 0 {
+		front_primitive["targets"] = json!(front_targets);
+		back_primitive["targets"] = json!(back_targets);
+		mesh["weights"] = json!(vec![0.0; targets_count]);
+
+		// The key frame is shown first, then each morph target in turn
+		let times: Vec<f32> = (0..=targets_count).map(|index| (index as f64 * options.frame_duration) as f32).collect();
+		let mut weights: Vec<f32> = vec![0.0; times.len() * targets_count];
+		for target_index in 0..targets_count {
+			weights[(target_index + 1) * targets_count + target_index] = 1.0;
+		}
+		let input_accessor = buffer.push_floats(&times, 1, None, true);
+		let output_accessor = buffer.push_floats(&weights, 1, None, false);
+		animations.push(json!({
+			"name": "frames",
+			"samplers": [{ "input": input_accessor, "output": output_accessor, "interpolation": "LINEAR" }],
+			"channels": [{ "sampler": 0, "target": { "node": 0, "path": "weights" } }],
+		}));
+	}
+	mesh["primitives"] = json!([front_primitive, back_primitive]);
+
+	let mut document = json!({
+		"asset": { "version": "2.0", "generator": fold.creator },
+		"scene": 0,
+		"scenes": [{ "nodes": [0] }],
+		"nodes": [{ "mesh": 0, "name": mesh["name"] }],
+		"meshes": [mesh],
+		"materials": [material("front", options.front_color), material("back", options.back_color)],
+		"buffers": [{ "byteLength": buffer.bytes.len() }],
+		"bufferViews": buffer.buffer_views,
+		"accessors": buffer.accessors,
+	});
+	if animations.len() > 0 { document["animations"] = json!(animations); }
+	return Ok((document, buffer.bytes));
+}
+
+pub fn export_gltf(fold: &Fold, options: &GltfOptions) -> Result<String, GltfError> {
+	let (mut document, bytes) = make_gltf(fold, options)?;
+	document["buffers"][0]["uri"] = json!(format!("data:application/octet-stream;base64,{}", encode_base64(&bytes)));
+	return Ok(document.to_string());
+}
+
+pub fn export_glb(fold: &Fold, options: &GltfOptions) -> Result<Vec<u8>, GltfError> {
+	let (document, mut bytes) = make_gltf(fold, options)?;
+	let mut json = document.to_string().into_bytes();
+	while !json.len().is_multiple_of(4) { json.push(b' '); }
+	while !bytes.len().is_multiple_of(4) { bytes.push(0); }
+
+	let length = 12 + 8 + json.len() + 8 + bytes.len();
+	let mut glb: Vec<u8> = Vec::with_capacity(length);
+	glb.extend_from_slice(b"glTF");
+	glb.extend_from_slice(&2u32.to_le_bytes());
+	glb.extend_from_slice(&(length as u32).to_le_bytes());
+	glb.extend_from_slice(&(json.len() as u32).to_le_bytes());
+	glb.extend_from_slice(b"JSON");
+	glb.extend_from_slice(&json);
+	glb.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+	glb.extend_from_slice(b"BIN\0");
+	glb.extend_from_slice(&bytes);
+	return Ok(glb);
+}
\ No newline at end of file
@@ -0,0 +1,13 @@
+mod error;
+mod base64;
+mod buffer;
+mod export;
+
+pub use error::GltfError;
+
+pub use base64::encode_base64;
+
+pub use export::GltfOptions;
+pub use export::make_gltf;
+pub use export::export_gltf;
+pub use export::export_glb;
\ No newline at end of file
@@ -0,0 +1,14 @@
+mod crease_pattern;
+
+pub mod svg;
+pub mod oripa;
+pub mod obj;
+pub mod gltf;
+pub mod solid;
+pub mod dxf;
+pub mod binary;
+pub mod stream;
+
+pub use crease_pattern::Segment;
+pub use crease_pattern::make_crease_pattern;
+pub use crease_pattern::crease_pattern_segments;
\ No newline at end of file
@@ -0,0 +1,6 @@
+#[derive(Clone, Copy, Debug)]
+pub enum ObjError {
+	InvalidNumber,
+	InvalidIndex,
+	InvalidFace,
+}
\ No newline at end of file
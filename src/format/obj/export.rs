@@ -0,0 +1,29 @@
+use std::fmt::Write;
+
+use crate::fold::{Frame, EdgeAssignment};
+
+pub fn export_obj(frame: &Frame, crease_lines: bool) -> String {
+	let graph = &frame.graph;
+	let mut obj = String::new();
+	if frame.title.len() > 0 { let _ = writeln!(obj, "o {}", frame.title.replace(char::is_whitespace, "_")); }
+
+	for coordinates in &graph.vertices_coordinates {
+		let coordinate = |index: usize| coordinates.get(index).map(|value| value.to_f64()).unwrap_or(0.0);
+		let _ = writeln!(obj, "v {} {} {}", coordinate(0), coordinate(1), coordinate(2));
+	}
+
+	if frame.classes.len() > 0 { let _ = writeln!(obj, "g {}", frame.classes.join(" ")); }
+	for face_vertices in &graph.faces_vertices {
+		let indices: Vec<String> = face_vertices.iter().map(|vertex_index| (vertex_index + 1).to_string()).collect();
+		let _ = writeln!(obj, "f {}", indices.join(" "));
+	}
+
+	if crease_lines {
+		for (edge_index, edge_vertices) in graph.edges_vertices.iter().enumerate() {
+			let assignment = graph.edges_assignment.get(edge_index).copied().unwrap_or(EdgeAssignment::Unknown);
+			if assignment == EdgeAssignment::Boundary { continue; }
+			let _ = writeln!(obj, "l {} {}", edge_vertices[0] + 1, edge_vertices[1] + 1);
+		}
+	}
+	return obj;
+}
\ No newline at end of file
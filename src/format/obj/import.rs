@@ -0,0 +1,113 @@
+use std::collections::HashMap;
+
+use crate::fold::{Fold, Graph, EdgeAssignment, FrameClass};
+use crate::graph::make::make_faces_normals;
+use crate::real::Real;
+use super::error::ObjError;
+
+// Indices are 1-based, negative indices count back from the last vertex
+fn parse_index(token: &str, vertices_count: usize) -> Result<usize, ObjError> {
+	let index_token = token.split('/').next().unwrap_or_default();
+	let index = index_token.parse::<i64>().map_err(|_| ObjError::InvalidIndex)?;
+	let vertex_index = if index < 0 { vertices_count as i64 + index } else { index - 1 };
+	if vertex_index < 0 || vertex_index >= vertices_count as i64 { return Err(ObjError::InvalidIndex); }
+	return Ok(vertex_index as usize);
+}
+
+fn is_coplanar(a: [f64; 3], b: [f64; 3]) -> bool {
+	let dot = a[0] * b[0] + a[1] * b[1] + a[2] * b[2];
+	return dot >= 1.0 - 1e-9;
+}
+
+pub fn import_obj(obj: &str) -> Result<Fold, ObjError> {
+	let mut graph = Graph::new();
+	let mut lines: Vec<[usize; 2]> = Vec::new();
+	let mut classes: Vec<String> = Vec::new();
+	let mut title = String::new();
+
+	for line in obj.lines() {
+		let line = line.split('#').next().unwrap_or_default();
+		let mut tokens = line.split_whitespace();
+		let keyword = match tokens.next() {
+			Some(keyword) => keyword,
+			None => continue,
+		};
+		let arguments: Vec<&str> = tokens.collect();
+		let vertices_count = graph.vertices_coordinates.len();
+
+		match keyword {
+			"v" => {
+				if arguments.len() < 3 { return Err(ObjError::InvalidNumber); }
+				let mut coordinates: Vec<Real> = Vec::new();
+				for argument in &arguments[0..3] {
+					let value = argument.parse::<f64>().map_err(|_| ObjError::InvalidNumber)?;
+					coordinates.push(Real::from_f64(value));
+				}
+				graph.vertices_coordinates.push(coordinates);
+			},
+			"f" => {
+				if arguments.len() < 3 { return Err(ObjError::InvalidFace); }
+				let face_vertices = arguments.iter()
+					.map(|argument| parse_index(argument, vertices_count))
+					.collect::<Result<Vec<usize>, ObjError>>()?;
+				graph.faces_vertices.push(face_vertices);
+			},
+			"l" => {
+				let line_vertices = arguments.iter()
+					.map(|argument| parse_index(argument, vertices_count))
+					.collect::<Result<Vec<usize>, ObjError>>()?;
+				lines.extend(line_vertices.windows(2).map(|pair| [pair[0], pair[1]]));
+			},
+			"o" => title = arguments.join(" "),
+			"g" => {
+				// Only group names that are frame classes are kept
+				for argument in arguments {
+					let is_class = serde_json::from_value::<FrameClass>(serde_json::Value::from(argument)).is_ok();
+					if is_class && !classes.iter().any(|class| class == argument) { classes.push(String::from(argument)); }
+				}
+			},
+			_ => {},
+		}
+	}
+
+	let mut edges_faces: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+	for (face_index, face_vertices) in graph.faces_vertices.iter().enumerate() {
+		let d = face_vertices.len();
+		for index in 0..d {
+			let (a, b) = (face_vertices[index], face_vertices[(index + 1) % d]);
+			let key = (usize::min(a, b), usize::max(a, b));
+			if !edges_faces.contains_key(&key) {
+				graph.edges_vertices.push(vec![a, b]);
+			}
+			edges_faces.entry(key).or_default().push(face_index);
+		}
+	}
+
+	let faces_normals = make_faces_normals(&graph);
+	for edge_vertices in &graph.edges_vertices {
+		let key = (usize::min(edge_vertices[0], edge_vertices[1]), usize::max(edge_vertices[0], edge_vertices[1]));
+		let assignment = match edges_faces[&key].as_slice() {
+			[_] => EdgeAssignment::Boundary,
+			[a, b] if is_coplanar(faces_normals[*a], faces_normals[*b]) => EdgeAssignment::Flat,
+			_ => EdgeAssignment::Unknown,
+		};
+		graph.edges_assignment.push(assignment);
+	}
+
+	for [a, b] in lines {
+		let key = (usize::min(a, b), usize::max(a, b));
+		if a == b || edges_faces.contains_key(&key) { continue; }
+		edges_faces.insert(key, Vec::new());
+		graph.edges_vertices.push(vec![a, b]);
+		graph.edges_assignment.push(EdgeAssignment::Unknown);
+	}
+
+	let mut fold = Fold::new();
+	fold.classes.push(String::from("singleModel"));
+	fold.key_frame.title = title;
+	if classes.len() == 0 { classes.push(String::from("foldedForm")); }
+	fold.key_frame.classes = classes;
+	fold.key_frame.attributes.push(String::from("3D"));
+	fold.key_frame.graph = graph;
+	return Ok(fold);
+}
\ No newline at end of file
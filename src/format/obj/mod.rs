@@ -0,0 +1,8 @@
+mod error;
+mod export;
+mod import;
+
+pub use error::ObjError;
+
+pub use export::export_obj;
+pub use import::import_obj;
\ No newline at end of file
@@ -0,0 +1,31 @@
+use std::fmt::Write;
+
+use crate::fold::{Fold, Graph};
+use crate::format::crease_pattern::{Segment, make_crease_pattern, crease_pattern_segments};
+use super::error::OripaError;
+use super::line_type::{line_type_assignment, assignment_line_type};
+
+pub fn import_cp(cp: &str) -> Result<Fold, OripaError> {
+	let mut segments: Vec<Segment> = Vec::new();
+	for line in cp.lines() {
+		let tokens: Vec<&str> = line.split_whitespace().collect();
+		if tokens.len() == 0 { continue; }
+		if tokens.len() != 5 { return Err(OripaError::InvalidLine); }
+
+		let line_type = tokens[0].parse::<i64>().map_err(|_| OripaError::InvalidLineType)?;
+		let mut values = [0.0; 4];
+		for (index, token) in tokens[1..].iter().enumerate() {
+			values[index] = token.parse::<f64>().map_err(|_| OripaError::InvalidNumber)?;
+		}
+		segments.push(([values[0], values[1]], [values[2], values[3]], line_type_assignment(line_type)?));
+	}
+	return Ok(make_crease_pattern(&segments));
+}
+
+pub fn export_cp(graph: &Graph) -> String {
+	let mut cp = String::new();
+	for (a, b, assignment) in crease_pattern_segments(graph) {
+		let _ = writeln!(cp, "{} {} {} {} {}", assignment_line_type(assignment), a[0], a[1], b[0], b[1]);
+	}
+	return cp;
+}
\ No newline at end of file
@@ -0,0 +1,7 @@
+#[derive(Clone, Copy, Debug)]
+pub enum OripaError {
+	InvalidXml,
+	InvalidNumber,
+	InvalidLine,
+	InvalidLineType,
+}
\ No newline at end of file
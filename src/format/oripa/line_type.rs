@@ -0,0 +1,22 @@
+use crate::fold::EdgeAssignment;
+use super::error::OripaError;
+
+pub fn line_type_assignment(line_type: i64) -> Result<EdgeAssignment, OripaError> {
+	return match line_type {
+		0 => Ok(EdgeAssignment::Flat),
+		1 => Ok(EdgeAssignment::Boundary),
+		2 => Ok(EdgeAssignment::Mountain),
+		3 => Ok(EdgeAssignment::Valley),
+		_ => Err(OripaError::InvalidLineType),
+	};
+}
+
+// ORIPA has no unassigned or join lines, these are written as auxiliary lines
+pub fn assignment_line_type(assignment: EdgeAssignment) -> i64 {
+	return match assignment {
+		EdgeAssignment::Boundary | EdgeAssignment::Cut => 1,
+		EdgeAssignment::Mountain => 2,
+		EdgeAssignment::Valley => 3,
+		EdgeAssignment::Flat | EdgeAssignment::Unknown | EdgeAssignment::Join => 0,
+	};
+}
\ No newline at end of file
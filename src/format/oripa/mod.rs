@@ -0,0 +1,15 @@
+mod error;
+mod line_type;
+mod cp;
+mod opx;
+
+pub use error::OripaError;
+
+pub use line_type::line_type_assignment;
+pub use line_type::assignment_line_type;
+
+pub use cp::import_cp;
+pub use cp::export_cp;
+
+pub use opx::import_opx;
+pub use opx::export_opx;
\ No newline at end of file
@@ -0,0 +1,95 @@
+use std::fmt::Write;
+
+use roxmltree::{Document, Node};
+
+use crate::fold::Fold;
+use crate::format::crease_pattern::{Segment, make_crease_pattern, crease_pattern_segments};
+use super::error::OripaError;
+use super::line_type::{line_type_assignment, assignment_line_type};
+
+// Properties are written by java.beans.XMLEncoder as <void property="name"><type>value</type></void>
+fn property<'a>(node: &Node<'a, '_>, name: &str) -> Option<&'a str> {
+	return node.children()
+		.find(|child| child.has_tag_name("void") && child.attribute("property") == Some(name))
+		.and_then(|child| child.children().find(|value| value.is_element()))
+		.map(|value| value.text().unwrap_or_default());
+}
+
+fn number_property(node: &Node, name: &str) -> Result<f64, OripaError> {
+	return match property(node, name) {
+		Some(value) => value.trim().parse::<f64>().map_err(|_| OripaError::InvalidNumber),
+		None => Ok(0.0),
+	};
+}
+
+pub fn import_opx(opx: &str) -> Result<Fold, OripaError> {
+	let document = Document::parse(opx).map_err(|_| OripaError::InvalidXml)?;
+	let mut segments: Vec<Segment> = Vec::new();
+
+	let lines = document.descendants().filter(|node| node.has_tag_name("object") && node.attribute("class") == Some("oripa.OriLineProxy"));
+	for line in lines {
+		let line_type = number_property(&line, "type")?;
+		if line_type.fract() != 0.0 { return Err(OripaError::InvalidLineType); }
+		let a = [number_property(&line, "x0")?, number_property(&line, "y0")?];
+		let b = [number_property(&line, "x1")?, number_property(&line, "y1")?];
+		segments.push((a, b, line_type_assignment(line_type as i64)?));
+	}
+
+	let mut fold = make_crease_pattern(&segments);
+	let data_set = document.descendants().find(|node| node.has_tag_name("object") && node.attribute("class") == Some("oripa.DataSet"));
+	if let Some(data_set) = data_set {
+		fold.title = String::from(property(&data_set, "title").unwrap_or_default());
+		fold.author = String::from(property(&data_set, "originalAuthorName").unwrap_or_default());
+		fold.description = String::from(property(&data_set, "memo").unwrap_or_default());
+	}
+	return Ok(fold);
+}
+
+fn escape(text: &str) -> String {
+	return text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;");
+}
+
+fn write_property(opx: &mut String, indent: &str, name: &str, value_type: &str, value: &str) {
+	let _ = writeln!(opx, "{indent}<void property=\"{name}\">");
+	let _ = writeln!(opx, "{indent} <{value_type}>{value}</{value_type}>");
+	let _ = writeln!(opx, "{indent}</void>");
+}
+
+fn decimal(value: f64) -> String {
+	return format!("{:?}", value);
+}
+
+pub fn export_opx(fold: &Fold) -> String {
+	let segments = crease_pattern_segments(&fold.key_frame.graph);
+	let mut paper_size: f64 = 0.0;
+	for (a, b, _) in &segments {
+		for point in [a, b] { paper_size = paper_size.max(point[0].abs()).max(point[1].abs()); }
+	}
+
+	let mut opx = String::new();
+	let _ = writeln!(opx, "<?xml version=\"1.0\" encoding=\"UTF-8\"?>");
+	let _ = writeln!(opx, "<java version=\"1.5.0_05\" class=\"java.beans.XMLDecoder\">");
+	let _ = writeln!(opx, " <object class=\"oripa.DataSet\">");
+	let _ = writeln!(opx, "  <void property=\"lines\">");
+	let _ = writeln!(opx, "   <array class=\"oripa.OriLineProxy\" length=\"{}\">", segments.len());
+	for (index, (a, b, assignment)) in segments.iter().enumerate() {
+		let _ = writeln!(opx, "    <void index=\"{index}\">");
+		let _ = writeln!(opx, "     <object class=\"oripa.OriLineProxy\">");
+		write_property(&mut opx, "      ", "type", "int", &assignment_line_type(*assignment).to_string());
+		write_property(&mut opx, "      ", "x0", "double", &decimal(a[0]));
+		write_property(&mut opx, "      ", "x1", "double", &decimal(b[0]));
+		write_property(&mut opx, "      ", "y0", "double", &decimal(a[1]));
+		write_property(&mut opx, "      ", "y1", "double", &decimal(b[1]));
+		let _ = writeln!(opx, "     </object>");
+		let _ = writeln!(opx, "    </void>");
+	}
+	let _ = writeln!(opx, "   </array>");
+	let _ = writeln!(opx, "  </void>");
+	write_property(&mut opx, "  ", "paperSize", "double", &decimal(2.0 * paper_size));
+	write_property(&mut opx, "  ", "title", "string", &escape(&fold.title));
+	write_property(&mut opx, "  ", "originalAuthorName", "string", &escape(&fold.author));
+	write_property(&mut opx, "  ", "memo", "string", &escape(&fold.description));
+	let _ = writeln!(opx, " </object>");
+	let _ = writeln!(opx, "</java>");
+	return opx;
+}
\ No newline at end of file
@@ -0,0 +1,137 @@
+use std::collections::HashMap;
+
+use crate::fold::Graph;
+use crate::graph::make::{make_face_triangles, make_face_normal};
+
+#[derive(Clone, Debug)]
+pub struct SolidOptions {
+	pub thickness: f64,
+	pub crease_gap: f64,
+}
+
+impl Default for SolidOptions {
+	fn default() -> Self {
+		return Self { thickness: 0.0, crease_gap: 0.0 }
+	}
+}
+
+impl SolidOptions {
+	pub fn new() -> Self {
+		return Self { ..Default::default() }
+	}
+}
+
+#[derive(Default, Clone, Debug)]
+pub struct Solid {
+	pub vertices: Vec<[f64; 3]>,
+	pub faces: Vec<Vec<usize>>,
+	pub triangles: Vec<[usize; 3]>,
+}
+
+fn add(a: [f64; 3], b: [f64; 3]) -> [f64; 3] { return [a[0] + b[0], a[1] + b[1], a[2] + b[2]]; }
+fn sub(a: [f64; 3], b: [f64; 3]) -> [f64; 3] { return [a[0] - b[0], a[1] - b[1], a[2] - b[2]]; }
+fn scale(a: [f64; 3], s: f64) -> [f64; 3] { return [a[0] * s, a[1] * s, a[2] * s]; }
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 { return a[0] * b[0] + a[1] * b[1] + a[2] * b[2]; }
+
+fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+	return [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]];
+}
+
+fn normalize(a: [f64; 3]) -> [f64; 3] {
+	let length = dot(a, a).sqrt();
+	if length == 0.0 { return a; }
+	return scale(a, 1.0 / length);
+}
+
+pub fn triangle_normal(a: [f64; 3], b: [f64; 3], c: [f64; 3]) -> [f64; 3] {
+	return normalize(cross(sub(b, a), sub(c, a)));
+}
+
+fn vertex_position(graph: &Graph, vertex_index: usize) -> [f64; 3] {
+	let coordinates = &graph.vertices_coordinates[vertex_index];
+	let coordinate = |index: usize| coordinates.get(index).map(|value| value.to_f64()).unwrap_or(0.0);
+	return [coordinate(0), coordinate(1), coordinate(2)];
+}
+
+// Moves every polygon vertex inward so each edge is offset by its own distance
+fn inset_polygon(points: &[[f64; 3]], normal: [f64; 3], distances: &[f64]) -> Vec<[f64; 3]> {
+	let d = points.len();
+	let inward: Vec<[f64; 3]> = (0..d).map(|index| normalize(cross(normal, sub(points[(index + 1) % d], points[index])))).collect();
+	return (0..d).map(|index| {
+		let previous = (index + d - 1) % d;
+		let (n1, n2) = (inward[previous], inward[index]);
+		let (d1, d2) = (distances[previous], distances[index]);
+		let c = dot(n1, n2);
+		let determinant = 1.0 - c * c;
+		if determinant < 1e-12 { return add(points[index], scale(n2, d2)); }
+		let alpha = (d1 - c * d2) / determinant;
+		let beta = (d2 - c * d1) / determinant;
+		return add(points[index], add(scale(n1, alpha), scale(n2, beta)));
+	}).collect();
+}
+
+pub fn make_solid(graph: &Graph, options: &SolidOptions) -> Solid {
+	let mut solid = Solid::default();
+	if options.thickness == 0.0 && options.crease_gap == 0.0 {
+		solid.vertices = (0..graph.vertices_coordinates.len()).map(|vertex_index| vertex_position(graph, vertex_index)).collect();
+		solid.faces = graph.faces_vertices.clone();
+		for face_vertices in &graph.faces_vertices {
+			solid.triangles.extend(make_face_triangles(graph, face_vertices));
+		}
+		return solid;
+	}
+
+	// Edges shared by several faces are creases, the faces are pulled away from them
+	let mut edges_faces_count: HashMap<(usize, usize), usize> = HashMap::new();
+	for face_vertices in &graph.faces_vertices {
+		let d = face_vertices.len();
+		for index in 0..d {
+			let (a, b) = (face_vertices[index], face_vertices[(index + 1) % d]);
+			*edges_faces_count.entry((usize::min(a, b), usize::max(a, b))).or_default() += 1;
+		}
+	}
+
+	let half_thickness = options.thickness / 2.0;
+	for face_vertices in &graph.faces_vertices {
+		let d = face_vertices.len();
+		if d < 3 { continue; }
+		let normal = make_face_normal(graph, face_vertices);
+		let points: Vec<[f64; 3]> = face_vertices.iter().map(|&vertex_index| vertex_position(graph, vertex_index)).collect();
+		let distances: Vec<f64> = (0..d).map(|index| {
+			let (a, b) = (face_vertices[index], face_vertices[(index + 1) % d]);
+			let is_crease = edges_faces_count[&(usize::min(a, b), usize::max(a, b))] > 1;
+			if is_crease { options.crease_gap / 2.0 } else { 0.0 }
+		}).collect();
+		let points = inset_polygon(&points, normal, &distances);
+
+		let local = |vertex_index: usize| face_vertices.iter().position(|&v| v == vertex_index).unwrap();
+		let face_triangles: Vec<[usize; 3]> = make_face_triangles(graph, face_vertices).iter()
+			.map(|triangle| triangle.map(local))
+			.collect();
+
+		let top = solid.vertices.len();
+		if options.thickness == 0.0 {
+			solid.vertices.extend(points);
+			solid.faces.push((top..top + d).collect());
+			solid.triangles.extend(face_triangles.iter().map(|triangle| triangle.map(|index| top + index)));
+			continue;
+		}
+
+		let bottom = top + d;
+		solid.vertices.extend(points.iter().map(|point| add(*point, scale(normal, half_thickness))));
+		solid.vertices.extend(points.iter().map(|point| sub(*point, scale(normal, half_thickness))));
+		solid.faces.push((top..top + d).collect());
+		solid.faces.push((bottom..bottom + d).rev().collect());
+		for triangle in &face_triangles {
+			solid.triangles.push(triangle.map(|index| top + index));
+			solid.triangles.push([bottom + triangle[0], bottom + triangle[2], bottom + triangle[1]]);
+		}
+		for index in 0..d {
+			let next = (index + 1) % d;
+			solid.faces.push(vec![bottom + index, bottom + next, top + next, top + index]);
+			solid.triangles.push([bottom + index, bottom + next, top + next]);
+			solid.triangles.push([bottom + index, top + next, top + index]);
+		}
+	}
+	return solid;
+}
\ No newline at end of file
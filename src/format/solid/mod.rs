@@ -0,0 +1,11 @@
+mod mesh;
+mod stl;
+mod ply;
+
+pub use mesh::Solid;
+pub use mesh::SolidOptions;
+pub use mesh::make_solid;
+
+pub use stl::export_stl_ascii;
+pub use stl::export_stl_binary;
+pub use ply::export_ply;
\ No newline at end of file
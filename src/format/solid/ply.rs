@@ -0,0 +1,29 @@
+use std::fmt::Write;
+
+use crate::fold::Frame;
+use super::mesh::{SolidOptions, make_solid};
+
+pub fn export_ply(frame: &Frame, options: &SolidOptions) -> String {
+	let solid = make_solid(&frame.graph, options);
+	let mut ply = String::new();
+	let _ = writeln!(ply, "ply");
+	let _ = writeln!(ply, "format ascii 1.0");
+	if frame.title.len() > 0 { let _ = writeln!(ply, "comment {}", frame.title.replace('\n', " ")); }
+	let _ = writeln!(ply, "element vertex {}", solid.vertices.len());
+	let _ = writeln!(ply, "property double x");
+	let _ = writeln!(ply, "property double y");
+	let _ = writeln!(ply, "property double z");
+	let _ = writeln!(ply, "element face {}", solid.faces.len());
+	// Crease pattern faces are written whole, so their size may not fit a uchar
+	let _ = writeln!(ply, "property list uint int vertex_indices");
+	let _ = writeln!(ply, "end_header");
+
+	for point in &solid.vertices {
+		let _ = writeln!(ply, "{} {} {}", point[0], point[1], point[2]);
+	}
+	for face_vertices in &solid.faces {
+		let indices: Vec<String> = face_vertices.iter().map(|vertex_index| vertex_index.to_string()).collect();
+		let _ = writeln!(ply, "{} {}", face_vertices.len(), indices.join(" "));
+	}
+	return ply;
+}
\ No newline at end of file
@@ -0,0 +1,55 @@
+use std::fmt::Write;
+
+use crate::fold::Frame;
+use super::mesh::{Solid, SolidOptions, make_solid, triangle_normal};
+
+fn solid_name(frame: &Frame) -> String {
+	if frame.title.len() == 0 { return String::from("fold"); }
+	return frame.title.replace(char::is_whitespace, "_");
+}
+
+fn triangle_points(solid: &Solid, triangle: &[usize; 3]) -> [[f64; 3]; 3] {
+	return triangle.map(|vertex_index| solid.vertices[vertex_index]);
+}
+
+pub fn export_stl_ascii(frame: &Frame, options: &SolidOptions) -> String {
+	let solid = make_solid(&frame.graph, options);
+	let name = solid_name(frame);
+	let mut stl = String::new();
+	let _ = writeln!(stl, "solid {}", name);
+	for triangle in &solid.triangles {
+		let [a, b, c] = triangle_points(&solid, triangle);
+		let normal = triangle_normal(a, b, c);
+		let _ = writeln!(stl, "  facet normal {} {} {}", normal[0], normal[1], normal[2]);
+		let _ = writeln!(stl, "    outer loop");
+		for point in [a, b, c] {
+			let _ = writeln!(stl, "      vertex {} {} {}", point[0], point[1], point[2]);
+		}
+		let _ = writeln!(stl, "    endloop");
+		let _ = writeln!(stl, "  endfacet");
+	}
+	let _ = writeln!(stl, "endsolid {}", name);
+	return stl;
+}
+
+pub fn export_stl_binary(frame: &Frame, options: &SolidOptions) -> Vec<u8> {
+	let solid = make_solid(&frame.graph, options);
+	let mut stl: Vec<u8> = Vec::with_capacity(84 + 50 * solid.triangles.len());
+
+	// The header is free text, it must not start with "solid" or readers take it for ASCII
+	let mut header = format!("fold {}", solid_name(frame)).into_bytes();
+	header.resize(80, b' ');
+	stl.extend_from_slice(&header);
+	stl.extend_from_slice(&(solid.triangles.len() as u32).to_le_bytes());
+
+	for triangle in &solid.triangles {
+		let [a, b, c] = triangle_points(&solid, triangle);
+		for vector in [triangle_normal(a, b, c), a, b, c] {
+			for value in vector {
+				stl.extend_from_slice(&(value as f32).to_le_bytes());
+			}
+		}
+		stl.extend_from_slice(&0u16.to_le_bytes());
+	}
+	return stl;
+}
\ No newline at end of file
@@ -0,0 +1,7 @@
+#[derive(Clone, Copy, Debug)]
+pub enum StreamError {
+	Io,
+	InvalidJson,
+	InvalidFrame,
+	MissingFrame,
+}
\ No newline at end of file
@@ -0,0 +1,9 @@
+mod error;
+mod scanner;
+mod reader;
+mod writer;
+
+pub use error::StreamError;
+
+pub use reader::FoldReader;
+pub use writer::FoldWriter;
\ No newline at end of file
@@ -0,0 +1,61 @@
+use std::fs::File;
+use std::io::{BufReader, Read, Seek, SeekFrom};
+use std::path::Path;
+
+use crate::fold::{Fold, Frame};
+use super::error::StreamError;
+use super::scanner::scan_fold;
+
+// The metadata and the key frame are read when opening, other frames only when they are requested
+pub struct FoldReader {
+	pub fold: Fold,
+	file: File,
+	frames_offsets: Vec<(u64, u64)>,
+}
+
+impl FoldReader {
+	pub fn open(path: impl AsRef<Path>) -> Result<Self, StreamError> {
+		let mut file = File::open(path).map_err(|_| StreamError::Io)?;
+		let scan = scan_fold(BufReader::new(&mut file))?;
+		let fold: Fold = serde_json::from_slice(&scan.header).map_err(|_| StreamError::InvalidJson)?;
+		return Ok(Self { fold, file, frames_offsets: scan.frames_offsets });
+	}
+
+	pub fn frames_count(&self) -> usize {
+		return self.frames_offsets.len();
+	}
+
+	pub fn get_frame(&mut self, frame_index: usize) -> Result<Frame, StreamError> {
+		if frame_index == 0 { return Ok(self.fold.key_frame.clone()); }
+		let &(start, end) = self.frames_offsets.get(frame_index - 1).ok_or(StreamError::MissingFrame)?;
+
+		let mut bytes = vec![0u8; (end - start) as usize];
+		self.file.seek(SeekFrom::Start(start)).map_err(|_| StreamError::Io)?;
+		self.file.read_exact(&mut bytes).map_err(|_| StreamError::Io)?;
+		return serde_json::from_slice(&bytes).map_err(|_| StreamError::InvalidFrame);
+	}
+
+	// Same as Fold::get_inherited_frame, frames that do not inherit are returned as they are
+	pub fn get_inherited_frame(&mut self, frame_index: usize) -> Result<Frame, StreamError> {
+		let mut frame = self.get_frame(frame_index)?;
+		if !frame.inherit { return Ok(frame); }
+		match frame.parent {
+			Some(index) if index <= self.frames_count() => {},
+			_ => return Ok(frame),
+		}
+
+		let mut inherited_frame = frame.clone();
+		for _ in 0..=self.frames_count() {
+			match frame.parent {
+				Some(index) => {
+					let frame_parent = self.get_frame(index)?;
+					inherited_frame.inherit_properties(&frame_parent);
+					if !frame_parent.inherit { break; }
+					frame = frame_parent;
+				},
+				None => break,
+			}
+		}
+		return Ok(inherited_frame);
+	}
+}
\ No newline at end of file
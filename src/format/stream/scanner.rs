@@ -0,0 +1,80 @@
+use std::io::Read;
+
+use super::error::StreamError;
+
+const FRAMES: &[u8] = b"file_frames";
+
+// The file without its frames, and the byte range of every frame in file_frames
+pub struct Scan {
+	pub header: Vec<u8>,
+	pub frames_offsets: Vec<(u64, u64)>,
+}
+
+// Single pass over the raw JSON, only strings and nesting are tracked so huge files stay cheap
+pub fn scan_fold(mut reader: impl Read) -> Result<Scan, StreamError> {
+	let mut scan = Scan { header: Vec::new(), frames_offsets: Vec::new() };
+	let mut depth: usize = 0;
+	let mut in_string = false;
+	let mut escape = false;
+	let mut in_frames = false;
+	let mut string: Vec<u8> = Vec::new();
+	let mut key: Vec<u8> = Vec::new();
+	let mut frame_start: u64 = 0;
+
+	let mut position: u64 = 0;
+	let mut buffer = vec![0u8; 1 << 16];
+	loop {
+		let length = reader.read(&mut buffer).map_err(|_| StreamError::Io)?;
+		if length == 0 { break; }
+
+		for &byte in &buffer[..length] {
+			let offset = position;
+			position += 1;
+
+			if in_string {
+				if escape {
+					escape = false;
+				} else if byte == b'\\' {
+					escape = true;
+				} else if byte == b'"' {
+					in_string = false;
+				}
+				if in_string && depth == 1 { string.push(byte); }
+				if !in_frames { scan.header.push(byte); }
+				continue;
+			}
+
+			match byte {
+				b'"' => {
+					in_string = true;
+					string.clear();
+				},
+				b':' if depth == 1 => key = std::mem::take(&mut string),
+				b'[' if depth == 1 && key == FRAMES && !in_frames => {
+					in_frames = true;
+					depth += 1;
+					scan.header.extend_from_slice(b"[]");
+					continue;
+				},
+				b'{' | b'[' => {
+					if in_frames && depth == 2 && byte == b'{' { frame_start = offset; }
+					depth += 1;
+				},
+				b'}' | b']' => {
+					if depth == 0 { return Err(StreamError::InvalidJson); }
+					depth -= 1;
+					if in_frames && depth == 2 && byte == b'}' { scan.frames_offsets.push((frame_start, offset + 1)); }
+					if in_frames && depth == 1 {
+						in_frames = false;
+						continue;
+					}
+				},
+				_ => {},
+			}
+			if !in_frames { scan.header.push(byte); }
+		}
+	}
+
+	if depth != 0 || in_string { return Err(StreamError::InvalidJson); }
+	return Ok(scan);
+}
\ No newline at end of file
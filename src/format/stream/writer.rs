@@ -0,0 +1,56 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use crate::fold::{Fold, Frame};
+use super::error::StreamError;
+
+// Writes the same JSON as serializing the whole Fold, file_frames are appended one by one
+pub struct FoldWriter<W: Write> {
+	writer: W,
+	frames_count: usize,
+}
+
+impl FoldWriter<BufWriter<File>> {
+	pub fn create(path: impl AsRef<Path>, fold: &Fold) -> Result<Self, StreamError> {
+		let file = File::create(path).map_err(|_| StreamError::Io)?;
+		return Self::new(BufWriter::new(file), fold);
+	}
+}
+
+impl<W: Write> FoldWriter<W> {
+	// Frames already in the fold are written first
+	pub fn new(mut writer: W, fold: &Fold) -> Result<Self, StreamError> {
+		let mut header = fold.clone();
+		header.frames.clear();
+		let mut json = serde_json::to_vec(&header).map_err(|_| StreamError::InvalidJson)?;
+		json.pop();
+		writer.write_all(&json).map_err(|_| StreamError::Io)?;
+
+		let mut fold_writer = Self { writer, frames_count: 0 };
+		for frame in &fold.frames {
+			fold_writer.push_frame(frame)?;
+		}
+		return Ok(fold_writer);
+	}
+
+	pub fn push_frame(&mut self, frame: &Frame) -> Result<(), StreamError> {
+		let separator: &[u8] = if self.frames_count == 0 { b",\"file_frames\":[" } else { b"," };
+		self.writer.write_all(separator).map_err(|_| StreamError::Io)?;
+		serde_json::to_writer(&mut self.writer, frame).map_err(|_| StreamError::InvalidFrame)?;
+		self.frames_count += 1;
+		return Ok(());
+	}
+
+	pub fn frames_count(&self) -> usize {
+		return self.frames_count;
+	}
+
+	// The file is only valid JSON once finished
+	pub fn finish(mut self) -> Result<W, StreamError> {
+		let end: &[u8] = if self.frames_count == 0 { b"}" } else { b"]}" };
+		self.writer.write_all(end).map_err(|_| StreamError::Io)?;
+		self.writer.flush().map_err(|_| StreamError::Io)?;
+		return Ok(self.writer);
+	}
+}
\ No newline at end of file
@@ -0,0 +1,52 @@
+const NAMED_COLORS: [(&str, [u8; 3]); 16] = [
+	("black", [0, 0, 0]),
+	("white", [255, 255, 255]),
+	("red", [255, 0, 0]),
+	("lime", [0, 255, 0]),
+	("green", [0, 128, 0]),
+	("blue", [0, 0, 255]),
+	("yellow", [255, 255, 0]),
+	("cyan", [0, 255, 255]),
+	("aqua", [0, 255, 255]),
+	("magenta", [255, 0, 255]),
+	("fuchsia", [255, 0, 255]),
+	("gray", [128, 128, 128]),
+	("grey", [128, 128, 128]),
+	("lightgray", [211, 211, 211]),
+	("orange", [255, 165, 0]),
+	("purple", [128, 0, 128]),
+];
+
+fn parse_hex(hex: &str) -> Option<[u8; 3]> {
+	if !hex.chars().all(|c| c.is_ascii_hexdigit()) { return None; }
+	let digit = |index: usize| u8::from_str_radix(&hex[index..index + 1], 16).unwrap();
+	let byte = |index: usize| u8::from_str_radix(&hex[index..index + 2], 16).unwrap();
+	return match hex.len() {
+		3 | 4 => Some([digit(0) * 17, digit(1) * 17, digit(2) * 17]),
+		6 | 8 => Some([byte(0), byte(2), byte(4)]),
+		_ => None,
+	};
+}
+
+fn parse_channel(channel: &str) -> Option<u8> {
+	let channel = channel.trim();
+	let value = match channel.strip_suffix('%') {
+		Some(percentage) => percentage.trim().parse::<f64>().ok()? * 2.55,
+		None => channel.parse::<f64>().ok()?,
+	};
+	return Some(value.round().clamp(0.0, 255.0) as u8);
+}
+
+pub fn parse_color(color: &str) -> Option<[u8; 3]> {
+	let color = color.trim().to_ascii_lowercase();
+	if let Some(hex) = color.strip_prefix('#') { return parse_hex(hex); }
+
+	let arguments = color.strip_prefix("rgba(").or_else(|| color.strip_prefix("rgb("));
+	if let Some(arguments) = arguments.and_then(|arguments| arguments.strip_suffix(')')) {
+		let channels: Vec<&str> = arguments.split([',', ' ']).filter(|channel| !channel.is_empty()).collect();
+		if channels.len() < 3 { return None; }
+		return Some([parse_channel(channels[0])?, parse_channel(channels[1])?, parse_channel(channels[2])?]);
+	}
+
+	return NAMED_COLORS.iter().find(|(name, _)| *name == color).map(|(_, rgb)| *rgb);
+}
\ No newline at end of file
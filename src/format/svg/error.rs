@@ -0,0 +1,7 @@
+#[derive(Clone, Copy, Debug)]
+pub enum SvgError {
+	InvalidXml,
+	InvalidNumber,
+	InvalidPath,
+	InvalidTransform,
+}
\ No newline at end of file
@@ -0,0 +1,228 @@
+use std::collections::{HashMap, BTreeSet};
+use std::fmt::Write;
+
+use crate::fold::{Frame, Graph, EdgeAssignment, FaceOrder};
+
+#[derive(Clone, Debug)]
+pub struct SvgExportOptions {
+	pub stroke_colors: HashMap<EdgeAssignment, String>,
+	pub stroke_width: Option<f64>,
+	pub draw_edges: bool,
+	pub fill_faces: bool,
+	pub front_fill: String,
+	pub back_fill: String,
+	pub vertex_labels: bool,
+	pub edge_labels: bool,
+	pub padding: f64,
+}
+
+impl Default for SvgExportOptions {
+	fn default() -> Self {
+		let stroke_colors = [
+			(EdgeAssignment::Boundary, "black"),
+			(EdgeAssignment::Mountain, "red"),
+			(EdgeAssignment::Valley, "blue"),
+			(EdgeAssignment::Flat, "lightgray"),
+			(EdgeAssignment::Unknown, "gray"),
+			(EdgeAssignment::Cut, "green"),
+			(EdgeAssignment::Join, "orange"),
+		];
+		return Self {
+			stroke_colors: stroke_colors.iter().map(|(assignment, color)| (*assignment, String::from(*color))).collect(),
+			stroke_width: None,
+			draw_edges: true,
+			fill_faces: false,
+			front_fill: String::from("white"),
+			back_fill: String::from("lightgray"),
+			vertex_labels: false,
+			edge_labels: false,
+			padding: 0.05,
+		}
+	}
+}
+
+impl SvgExportOptions {
+	pub fn new() -> Self {
+		return Self { ..Default::default() }
+	}
+}
+
+pub fn assignment_class(assignment: EdgeAssignment) -> &'static str {
+	return match assignment {
+		EdgeAssignment::Boundary => "boundary",
+		EdgeAssignment::Mountain => "mountain",
+		EdgeAssignment::Valley => "valley",
+		EdgeAssignment::Flat => "flat",
+		EdgeAssignment::Unknown => "unassigned",
+		EdgeAssignment::Cut => "cut",
+		EdgeAssignment::Join => "join",
+	};
+}
+
+fn vertex_position(graph: &Graph, vertex_index: usize) -> [f64; 2] {
+	let coordinates = &graph.vertices_coordinates[vertex_index];
+	let coordinate = |index: usize| coordinates.get(index).map(|value| value.to_f64()).unwrap_or(0.0);
+	return [coordinate(0), coordinate(1)];
+}
+
+fn signed_area(points: &[[f64; 2]]) -> f64 {
+	let d = points.len();
+	let mut area = 0.0;
+	for index in 0..d {
+		let (a, b) = (points[index], points[(index + 1) % d]);
+		area += a[0] * b[1] - a[1] * b[0];
+	}
+	return area / 2.0;
+}
+
+// Orders faces back to front, seen from the positive z axis
+fn painter_order(graph: &Graph, faces_area: &[f64]) -> Vec<usize> {
+	let faces_count = graph.faces_vertices.len();
+	let mut faces_above: Vec<Vec<usize>> = vec![Vec::new(); faces_count];
+	let mut below_count: Vec<usize> = vec![0; faces_count];
+
+	for (face, other_face, order) in &graph.face_orders {
+		if *face >= faces_count || *other_face >= faces_count { continue; }
+		let is_above = match order {
+			FaceOrder::Above => true,
+			FaceOrder::Below => false,
+			FaceOrder::Unknown => continue,
+		};
+		// The order is relative to the normal of the other face, which is flipped when it winds clockwise
+		let is_front = is_above == (faces_area[*other_face] >= 0.0);
+		let (back, front) = if is_front { (*other_face, *face) } else { (*face, *other_face) };
+		faces_above[back].push(front);
+		below_count[front] += 1;
+	}
+
+	let mut order: Vec<usize> = Vec::with_capacity(faces_count);
+	let mut ready: BTreeSet<usize> = (0..faces_count).filter(|&face| below_count[face] == 0).collect();
+	let mut is_drawn: Vec<bool> = vec![false; faces_count];
+	while order.len() < faces_count {
+		// Cyclic orders cannot be drawn exactly, the lowest remaining face is drawn next
+		let face = match ready.pop_first() {
+			Some(face) => face,
+			None => (0..faces_count).find(|&face| !is_drawn[face]).unwrap(),
+		};
+		if is_drawn[face] { continue; }
+		is_drawn[face] = true;
+		order.push(face);
+		for &front in &faces_above[face] {
+			below_count[front] = below_count[front].saturating_sub(1);
+			if below_count[front] == 0 && !is_drawn[front] { ready.insert(front); }
+		}
+	}
+	return order;
+}
+
+fn write_edge(svg: &mut String, options: &SvgExportOptions, assignment: EdgeAssignment, a: [f64; 2], b: [f64; 2]) {
+	let color = options.stroke_colors.get(&assignment).map(|color| color.as_str()).unwrap_or("black");
+	let _ = writeln!(svg,
+		"<line class=\"{}\" x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" stroke=\"{}\"/>",
+		assignment_class(assignment), a[0], a[1], b[0], b[1], color,
+	);
+}
+
+fn write_label(svg: &mut String, position: [f64; 2], font_size: f64, color: &str, label: usize) {
+	let _ = writeln!(svg,
+		"<text x=\"{}\" y=\"{}\" font-size=\"{}\" fill=\"{}\" text-anchor=\"middle\" dominant-baseline=\"middle\">{}</text>",
+		position[0], position[1], font_size, color, label,
+	);
+}
+
+pub fn export_svg(frame: &Frame, options: &SvgExportOptions) -> String {
+	let graph = &frame.graph;
+	let positions: Vec<[f64; 2]> = (0..graph.vertices_coordinates.len()).map(|vertex_index| vertex_position(graph, vertex_index)).collect();
+
+	let mut minimum = [f64::INFINITY, f64::INFINITY];
+	let mut maximum = [f64::NEG_INFINITY, f64::NEG_INFINITY];
+	for position in &positions {
+		for axis in 0..2 {
+			minimum[axis] = minimum[axis].min(position[axis]);
+			maximum[axis] = maximum[axis].max(position[axis]);
+		}
+	}
+	if positions.len() == 0 { (minimum, maximum) = ([0.0, 0.0], [1.0, 1.0]); }
+	let size = f64::max(maximum[0] - minimum[0], maximum[1] - minimum[1]).max(f64::MIN_POSITIVE);
+	let padding = size * options.padding;
+	let stroke_width = options.stroke_width.unwrap_or(size * 0.005);
+	let font_size = size * 0.03;
+
+	let mut svg = String::new();
+	let _ = writeln!(svg,
+		"<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{} {} {} {}\">",
+		minimum[0] - padding, minimum[1] - padding,
+		maximum[0] - minimum[0] + 2.0 * padding, maximum[1] - minimum[1] + 2.0 * padding,
+	);
+	if frame.title.len() > 0 { let _ = writeln!(svg, "<title>{}</title>", escape(&frame.title)); }
+
+	let faces_points: Vec<Vec<[f64; 2]>> = graph.faces_vertices.iter()
+		.map(|face_vertices| face_vertices.iter().map(|&vertex_index| positions[vertex_index]).collect())
+		.collect();
+	let faces_area: Vec<f64> = faces_points.iter().map(|points| signed_area(points)).collect();
+	let is_layered = options.fill_faces && graph.face_orders.len() > 0;
+	let edge_assignment = |edge_index: usize| graph.edges_assignment.get(edge_index).copied().unwrap_or(EdgeAssignment::Unknown);
+
+	// Layered faces are drawn with their own edges, so that the faces above hide the creases below them,
+	// sides without an edge keep the boundary color
+	if options.fill_faces {
+		let mut edges_map: HashMap<(usize, usize), usize> = HashMap::new();
+		if is_layered && options.draw_edges {
+			for (edge_index, edge_vertices) in graph.edges_vertices.iter().enumerate() {
+				edges_map.insert((edge_vertices[0].min(edge_vertices[1]), edge_vertices[0].max(edge_vertices[1])), edge_index);
+			}
+		}
+		let _ = writeln!(svg, "<g class=\"faces\" stroke-width=\"{}\" stroke-linecap=\"round\" stroke-linejoin=\"round\">", stroke_width);
+		for face_index in painter_order(graph, &faces_area) {
+			let points: Vec<String> = faces_points[face_index].iter().map(|point| format!("{},{}", point[0], point[1])).collect();
+			let (class, fill) = if faces_area[face_index] >= 0.0 {
+				("front", &options.front_fill)
+			} else {
+				("back", &options.back_fill)
+			};
+			let _ = writeln!(svg, "<polygon class=\"{}\" points=\"{}\" fill=\"{}\" stroke=\"none\"/>", class, points.join(" "), fill);
+			if !is_layered || !options.draw_edges { continue; }
+
+			let face_vertices = &graph.faces_vertices[face_index];
+			let d = face_vertices.len();
+			for index in 0..d {
+				let (u, v) = (face_vertices[index], face_vertices[(index + 1) % d]);
+				let assignment = edges_map.get(&(u.min(v), u.max(v))).map(|&edge_index| edge_assignment(edge_index)).unwrap_or(EdgeAssignment::Boundary);
+				write_edge(&mut svg, options, assignment, positions[u], positions[v]);
+			}
+		}
+		let _ = writeln!(svg, "</g>");
+	}
+
+	if options.draw_edges && !is_layered {
+		let _ = writeln!(svg, "<g class=\"edges\" stroke-width=\"{}\" stroke-linecap=\"round\">", stroke_width);
+		for (edge_index, edge_vertices) in graph.edges_vertices.iter().enumerate() {
+			write_edge(&mut svg, options, edge_assignment(edge_index), positions[edge_vertices[0]], positions[edge_vertices[1]]);
+		}
+		let _ = writeln!(svg, "</g>");
+	}
+
+	if options.edge_labels {
+		let _ = writeln!(svg, "<g class=\"edge-labels\">");
+		for (edge_index, edge_vertices) in graph.edges_vertices.iter().enumerate() {
+			let (a, b) = (positions[edge_vertices[0]], positions[edge_vertices[1]]);
+			write_label(&mut svg, [(a[0] + b[0]) / 2.0, (a[1] + b[1]) / 2.0], font_size, "darkgreen", edge_index);
+		}
+		let _ = writeln!(svg, "</g>");
+	}
+
+	if options.vertex_labels {
+		let _ = writeln!(svg, "<g class=\"vertex-labels\">");
+		for (vertex_index, position) in positions.iter().enumerate() {
+			write_label(&mut svg, *position, font_size, "purple", vertex_index);
+		}
+		let _ = writeln!(svg, "</g>");
+	}
+
+	svg.push_str("</svg>\n");
+	return svg;
+}
+
+fn escape(text: &str) -> String {
+	return text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;");
+}
\ No newline at end of file
@@ -0,0 +1,224 @@
+use std::collections::HashMap;
+
+use roxmltree::{Document, Node};
+
+use crate::fold::{Fold, EdgeAssignment};
+use crate::format::crease_pattern::{Segment, make_crease_pattern};
+use super::error::SvgError;
+use super::color::parse_color;
+use super::transform::{Transform, parse_transform};
+use super::path::{parse_path, parse_points};
+
+#[derive(Clone, Debug)]
+pub struct SvgImportOptions {
+	pub colors: HashMap<String, EdgeAssignment>,
+	pub classes: HashMap<String, EdgeAssignment>,
+	pub default_assignment: EdgeAssignment,
+	pub curve_segments: usize,
+}
+
+impl Default for SvgImportOptions {
+	fn default() -> Self {
+		let colors = [
+			("red", EdgeAssignment::Mountain),
+			("blue", EdgeAssignment::Valley),
+			("black", EdgeAssignment::Boundary),
+		];
+		let classes = [
+			("boundary", EdgeAssignment::Boundary),
+			("mountain", EdgeAssignment::Mountain),
+			("valley", EdgeAssignment::Valley),
+			("flat", EdgeAssignment::Flat),
+			("mark", EdgeAssignment::Flat),
+			("unassigned", EdgeAssignment::Unknown),
+			("cut", EdgeAssignment::Cut),
+			("join", EdgeAssignment::Join),
+		];
+		return Self {
+			colors: colors.iter().map(|(color, assignment)| (String::from(*color), *assignment)).collect(),
+			classes: classes.iter().map(|(class, assignment)| (String::from(*class), *assignment)).collect(),
+			default_assignment: EdgeAssignment::Unknown,
+			curve_segments: 16,
+		}
+	}
+}
+
+impl SvgImportOptions {
+	pub fn new() -> Self {
+		return Self { ..Default::default() }
+	}
+}
+
+fn style_property<'a>(node: &Node<'a, '_>, name: &str) -> Option<&'a str> {
+	let style = node.attribute("style")?;
+	return style.split(';')
+		.filter_map(|declaration| declaration.split_once(':'))
+		.find(|(property, _)| property.trim() == name)
+		.map(|(_, value)| value.trim());
+}
+
+// Presentation attributes and style properties are inherited from ancestor groups
+fn inherited_property<'a>(node: &Node<'a, '_>, name: &str) -> Option<&'a str> {
+	return node.ancestors()
+		.filter(|ancestor| ancestor.is_element())
+		.find_map(|ancestor| style_property(&ancestor, name).or_else(|| ancestor.attribute(name)))
+		.filter(|value| *value != "inherit");
+}
+
+fn node_transform(node: &Node) -> Result<Transform, SvgError> {
+	let mut transform = Transform::new();
+	for ancestor in node.ancestors().filter(|ancestor| ancestor.is_element()) {
+		if let Some(attribute) = ancestor.attribute("transform") {
+			transform = parse_transform(attribute)?.multiply(&transform);
+		}
+	}
+	return Ok(transform);
+}
+
+fn node_assignment(node: &Node, options: &SvgImportOptions) -> Option<EdgeAssignment> {
+	for ancestor in node.ancestors().filter(|ancestor| ancestor.is_element()) {
+		let classes = ancestor.attribute("class").unwrap_or_default();
+		for class in classes.split_whitespace() {
+			if let Some(assignment) = options.classes.get(class) { return Some(*assignment); }
+		}
+	}
+
+	let stroke = match inherited_property(node, "stroke") {
+		Some(stroke) => stroke,
+		None => return Some(options.default_assignment),
+	};
+	if stroke == "none" { return None; }
+	let rgb = parse_color(stroke);
+	for (color, assignment) in &options.colors {
+		if rgb.is_some() && parse_color(color) == rgb { return Some(*assignment); }
+	}
+	return Some(options.default_assignment);
+}
+
+// Absolute units in user units, em and ex taking the default font size of browsers
+const UNITS: [(&str, f64); 9] = [
+	("px", 1.0),
+	("in", 96.0),
+	("cm", 96.0 / 2.54),
+	("mm", 96.0 / 25.4),
+	("Q", 96.0 / 101.6),
+	("pt", 96.0 / 72.0),
+	("pc", 16.0),
+	("em", 16.0),
+	("ex", 8.0),
+];
+
+// An exponent needs digits after it, so that em and ex stay units
+fn number_end(value: &str) -> usize {
+	let bytes = value.as_bytes();
+	let mut end = 0;
+	if end < bytes.len() && (bytes[end] == b'+' || bytes[end] == b'-') { end += 1; }
+	while end < bytes.len() && (bytes[end].is_ascii_digit() || bytes[end] == b'.') { end += 1; }
+	if end < bytes.len() && (bytes[end] == b'e' || bytes[end] == b'E') {
+		let mut exponent_end = end + 1;
+		if exponent_end < bytes.len() && (bytes[exponent_end] == b'+' || bytes[exponent_end] == b'-') { exponent_end += 1; }
+		if exponent_end < bytes.len() && bytes[exponent_end].is_ascii_digit() {
+			while exponent_end < bytes.len() && bytes[exponent_end].is_ascii_digit() { exponent_end += 1; }
+			end = exponent_end;
+		}
+	}
+	return end;
+}
+
+fn parse_length(value: &str) -> Option<(f64, &str)> {
+	let value = value.trim();
+	let end = number_end(value);
+	let number = value[..end].parse::<f64>().ok()?;
+	return Some((number, value[end..].trim()));
+}
+
+// Percentages refer to the viewBox of the nearest svg element, or to its size when it has none
+fn viewport_size(node: &Node) -> [f64; 2] {
+	let Some(svg) = node.ancestors().find(|ancestor| ancestor.has_tag_name("svg")) else { return [100.0, 100.0]; };
+	if let Some(view_box) = svg.attribute("viewBox") {
+		let values: Vec<f64> = view_box.split(|c: char| c == ',' || c.is_whitespace())
+			.filter(|value| value.len() > 0)
+			.filter_map(|value| value.parse::<f64>().ok())
+			.collect();
+		if values.len() == 4 { return [values[2], values[3]]; }
+	}
+	let size = |name: &str, default: f64| svg.attribute(name)
+		.and_then(parse_length)
+		.filter(|(_, unit)| *unit != "%")
+		.and_then(|(number, unit)| to_user_units(number, unit))
+		.unwrap_or(default);
+	return [size("width", 100.0), size("height", 100.0)];
+}
+
+fn to_user_units(number: f64, unit: &str) -> Option<f64> {
+	if unit.len() == 0 { return Some(number); }
+	return UNITS.iter().find(|(name, _)| *name == unit).map(|(_, scale)| number * scale);
+}
+
+fn length(node: &Node, name: &str) -> Result<f64, SvgError> {
+	let value = node.attribute(name).unwrap_or("0");
+	let (number, unit) = parse_length(value).ok_or(SvgError::InvalidNumber)?;
+	if unit == "%" {
+		let [width, height] = viewport_size(node);
+		let reference = match name {
+			"x" | "x1" | "x2" | "width" => width,
+			"y" | "y1" | "y2" | "height" => height,
+			_ => ((width * width + height * height) / 2.0).sqrt(),
+		};
+		return Ok(number / 100.0 * reference);
+	}
+	return to_user_units(number, unit).ok_or(SvgError::InvalidNumber);
+}
+
+fn node_polylines(node: &Node, options: &SvgImportOptions) -> Result<Vec<Vec<[f64; 2]>>, SvgError> {
+	return match node.tag_name().name() {
+		"line" => Ok(vec![vec![
+			[length(node, "x1")?, length(node, "y1")?],
+			[length(node, "x2")?, length(node, "y2")?],
+		]]),
+		"polyline" => Ok(vec![parse_points(node.attribute("points").unwrap_or_default())?]),
+		"polygon" => {
+			let mut points = parse_points(node.attribute("points").unwrap_or_default())?;
+			if let Some(&first) = points.first() { points.push(first); }
+			Ok(vec![points])
+		},
+		"rect" => {
+			let (x, y) = (length(node, "x")?, length(node, "y")?);
+			let (width, height) = (length(node, "width")?, length(node, "height")?);
+			Ok(vec![vec![[x, y], [x + width, y], [x + width, y + height], [x, y + height], [x, y]]])
+		},
+		"path" => parse_path(node.attribute("d").unwrap_or_default(), options.curve_segments),
+		_ => Ok(Vec::new()),
+	};
+}
+
+fn is_rendered(node: &Node) -> bool {
+	return !node.ancestors().any(|ancestor| matches!(ancestor.tag_name().name(), "defs" | "clipPath" | "mask" | "marker" | "pattern" | "symbol"));
+}
+
+pub fn import_svg(svg: &str, options: &SvgImportOptions) -> Result<Fold, SvgError> {
+	let document = Document::parse(svg).map_err(|_| SvgError::InvalidXml)?;
+	let mut segments: Vec<Segment> = Vec::new();
+
+	for node in document.descendants().filter(|node| node.is_element() && is_rendered(node)) {
+		let polylines = node_polylines(&node, options)?;
+		if polylines.is_empty() { continue; }
+		let assignment = match node_assignment(&node, options) {
+			Some(assignment) => assignment,
+			None => continue,
+		};
+
+		let transform = node_transform(&node)?;
+		for polyline in polylines {
+			for pair in polyline.windows(2) {
+				segments.push((transform.apply(pair[0]), transform.apply(pair[1]), assignment));
+			}
+		}
+	}
+
+	let mut fold = make_crease_pattern(&segments);
+	if let Some(title) = document.descendants().find(|node| node.has_tag_name("title")) {
+		fold.title = String::from(title.text().unwrap_or_default().trim());
+	}
+	return Ok(fold);
+}
\ No newline at end of file
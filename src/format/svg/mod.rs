@@ -0,0 +1,23 @@
+mod error;
+mod color;
+mod transform;
+mod path;
+mod import;
+mod export;
+
+pub use error::SvgError;
+
+pub use color::parse_color;
+
+pub use transform::Transform;
+pub use transform::parse_transform;
+
+pub use path::parse_path;
+pub use path::parse_points;
+
+pub use import::SvgImportOptions;
+pub use import::import_svg;
+
+pub use export::SvgExportOptions;
+pub use export::assignment_class;
+pub use export::export_svg;
\ No newline at end of file
@@ -0,0 +1,256 @@
+use std::f64::consts::TAU;
+
+use super::error::SvgError;
+
+struct Parser<'a> {
+	bytes: &'a [u8],
+	index: usize,
+}
+
+impl<'a> Parser<'a> {
+	fn new(text: &'a str) -> Self {
+		return Self { bytes: text.as_bytes(), index: 0 }
+	}
+
+	fn skip_separators(&mut self) {
+		while self.index < self.bytes.len() && (self.bytes[self.index].is_ascii_whitespace() || self.bytes[self.index] == b',') {
+			self.index += 1;
+		}
+	}
+
+	fn peek(&mut self) -> Option<u8> {
+		self.skip_separators();
+		return self.bytes.get(self.index).copied();
+	}
+
+	fn has_number(&mut self) -> bool {
+		return matches!(self.peek(), Some(b'0'..=b'9' | b'-' | b'+' | b'.'));
+	}
+
+	fn number(&mut self) -> Result<f64, SvgError> {
+		self.skip_separators();
+		let start = self.index;
+		let is_digit = |parser: &Self| parser.index < parser.bytes.len() && parser.bytes[parser.index].is_ascii_digit();
+
+		if matches!(self.bytes.get(self.index), Some(b'-' | b'+')) { self.index += 1; }
+		while is_digit(self) { self.index += 1; }
+		if self.bytes.get(self.index) == Some(&b'.') {
+			self.index += 1;
+			while is_digit(self) { self.index += 1; }
+		}
+		if matches!(self.bytes.get(self.index), Some(b'e' | b'E')) {
+			let mantissa_end = self.index;
+			self.index += 1;
+			if matches!(self.bytes.get(self.index), Some(b'-' | b'+')) { self.index += 1; }
+			if is_digit(self) {
+				while is_digit(self) { self.index += 1; }
+			} else {
+				self.index = mantissa_end;
+			}
+		}
+
+		let text = core::str::from_utf8(&self.bytes[start..self.index]).map_err(|_| SvgError::InvalidNumber)?;
+		return text.parse::<f64>().map_err(|_| SvgError::InvalidNumber);
+	}
+
+	fn flag(&mut self) -> Result<bool, SvgError> {
+		let flag = self.peek().ok_or(SvgError::InvalidPath)?;
+		self.index += 1;
+		return match flag {
+			b'0' => Ok(false),
+			b'1' => Ok(true),
+			_ => Err(SvgError::InvalidPath),
+		};
+	}
+
+	fn point(&mut self) -> Result<[f64; 2], SvgError> {
+		return Ok([self.number()?, self.number()?]);
+	}
+}
+
+pub fn parse_numbers(text: &str) -> Result<Vec<f64>, SvgError> {
+	let mut parser = Parser::new(text);
+	let mut numbers: Vec<f64> = Vec::new();
+	while parser.has_number() { numbers.push(parser.number()?); }
+	if parser.peek().is_some() { return Err(SvgError::InvalidNumber); }
+	return Ok(numbers);
+}
+
+pub fn parse_points(text: &str) -> Result<Vec<[f64; 2]>, SvgError> {
+	let numbers = parse_numbers(text)?;
+	return Ok(numbers.chunks_exact(2).map(|pair| [pair[0], pair[1]]).collect());
+}
+
+fn vector_angle(u: [f64; 2], v: [f64; 2]) -> f64 {
+	return f64::atan2(u[0] * v[1] - u[1] * v[0], u[0] * v[0] + u[1] * v[1]);
+}
+
+// Converts the endpoint parameterization of an elliptical arc to its center and flattens it
+fn flatten_arc(from: [f64; 2], radii: [f64; 2], rotation: f64, large_arc: bool, sweep: bool, to: [f64; 2], segments: usize) -> Vec<[f64; 2]> {
+	if from == to { return Vec::new(); }
+	let (mut rx, mut ry) = (radii[0].abs(), radii[1].abs());
+	if rx == 0.0 || ry == 0.0 { return vec![to]; }
+
+	let (sin, cos) = rotation.to_radians().sin_cos();
+	let dx = (from[0] - to[0]) / 2.0;
+	let dy = (from[1] - to[1]) / 2.0;
+	let x1 = cos * dx + sin * dy;
+	let y1 = -sin * dx + cos * dy;
+
+	let lambda = (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+	if lambda > 1.0 {
+		rx *= lambda.sqrt();
+		ry *= lambda.sqrt();
+	}
+
+	let numerator = rx * rx * ry * ry - rx * rx * y1 * y1 - ry * ry * x1 * x1;
+	let denominator = rx * rx * y1 * y1 + ry * ry * x1 * x1;
+	let sign = if large_arc == sweep { -1.0 } else { 1.0 };
+	let coefficient = sign * (numerator / denominator).max(0.0).sqrt();
+	let cx1 = coefficient * rx * y1 / ry;
+	let cy1 = -coefficient * ry * x1 / rx;
+	let cx = cos * cx1 - sin * cy1 + (from[0] + to[0]) / 2.0;
+	let cy = sin * cx1 + cos * cy1 + (from[1] + to[1]) / 2.0;
+
+	let start = [(x1 - cx1) / rx, (y1 - cy1) / ry];
+	let end = [(-x1 - cx1) / rx, (-y1 - cy1) / ry];
+	let theta = vector_angle([1.0, 0.0], start);
+	let mut delta = vector_angle(start, end);
+	if !sweep && delta > 0.0 { delta -= TAU; }
+	if sweep && delta < 0.0 { delta += TAU; }
+
+	let count = ((delta.abs() / TAU * segments as f64).ceil() as usize).max(1);
+	let mut points: Vec<[f64; 2]> = (1..count).map(|index| {
+		let angle = theta + delta * index as f64 / count as f64;
+		let (x, y) = (rx * angle.cos(), ry * angle.sin());
+		[cos * x - sin * y + cx, sin * x + cos * y + cy]
+	}).collect();
+	points.push(to);
+	return points;
+}
+
+fn flatten_cubic(from: [f64; 2], control1: [f64; 2], control2: [f64; 2], to: [f64; 2], segments: usize) -> Vec<[f64; 2]> {
+	let mut points: Vec<[f64; 2]> = (1..segments).map(|index| {
+		let t = index as f64 / segments as f64;
+		let s = 1.0 - t;
+		let weights = [s * s * s, 3.0 * s * s * t, 3.0 * s * t * t, t * t * t];
+		let coordinate = |axis: usize| weights[0] * from[axis] + weights[1] * control1[axis] + weights[2] * control2[axis] + weights[3] * to[axis];
+		[coordinate(0), coordinate(1)]
+	}).collect();
+	points.push(to);
+	return points;
+}
+
+fn flatten_quadratic(from: [f64; 2], control: [f64; 2], to: [f64; 2], segments: usize) -> Vec<[f64; 2]> {
+	let control1 = [from[0] + 2.0 / 3.0 * (control[0] - from[0]), from[1] + 2.0 / 3.0 * (control[1] - from[1])];
+	let control2 = [to[0] + 2.0 / 3.0 * (control[0] - to[0]), to[1] + 2.0 / 3.0 * (control[1] - to[1])];
+	return flatten_cubic(from, control1, control2, to, segments);
+}
+
+fn reflect(point: [f64; 2], center: [f64; 2]) -> [f64; 2] {
+	return [2.0 * center[0] - point[0], 2.0 * center[1] - point[1]];
+}
+
+// Returns the path as polylines, one per subpath, with curves flattened to segments
+pub fn parse_path(path: &str, curve_segments: usize) -> Result<Vec<Vec<[f64; 2]>>, SvgError> {
+	let segments = curve_segments.max(1);
+	let mut parser = Parser::new(path);
+	let mut polylines: Vec<Vec<[f64; 2]>> = Vec::new();
+	let mut polyline: Vec<[f64; 2]> = Vec::new();
+	let mut current = [0.0, 0.0];
+	let mut start = [0.0, 0.0];
+	let mut last_control: Option<[f64; 2]> = None;
+	let mut previous_command: Option<u8> = None;
+
+	while let Some(next) = parser.peek() {
+		let command = if next.is_ascii_alphabetic() {
+			parser.index += 1;
+			next
+		} else {
+			match previous_command {
+				Some(b'M') => b'L',
+				Some(b'm') => b'l',
+				Some(b'Z' | b'z') | None => return Err(SvgError::InvalidPath),
+				Some(command) => command,
+			}
+		};
+
+		let relative = command.is_ascii_lowercase();
+		let offset = |point: [f64; 2]| if relative { [point[0] + current[0], point[1] + current[1]] } else { point };
+		let mut control: Option<[f64; 2]> = None;
+		let points: Vec<[f64; 2]> = match command.to_ascii_uppercase() {
+			b'M' => {
+				let point = offset(parser.point()?);
+				if polyline.len() > 1 { polylines.push(polyline); }
+				polyline = vec![point];
+				start = point;
+				current = point;
+				previous_command = Some(command);
+				last_control = None;
+				continue;
+			},
+			b'Z' => {
+				if polyline.len() > 1 && polyline.last() != Some(&start) { polyline.push(start); }
+				if polyline.len() > 1 { polylines.push(polyline); }
+				polyline = vec![start];
+				current = start;
+				previous_command = Some(command);
+				last_control = None;
+				continue;
+			},
+			b'L' => vec![offset(parser.point()?)],
+			b'H' => {
+				let x = parser.number()? + if relative { current[0] } else { 0.0 };
+				vec![[x, current[1]]]
+			},
+			b'V' => {
+				let y = parser.number()? + if relative { current[1] } else { 0.0 };
+				vec![[current[0], y]]
+			},
+			b'C' | b'S' => {
+				let control1 = if command.eq_ignore_ascii_case(&b'C') {
+					offset(parser.point()?)
+				} else {
+					match (previous_command.map(|c| c.to_ascii_uppercase()), last_control) {
+						(Some(b'C' | b'S'), Some(last)) => reflect(last, current),
+						_ => current,
+					}
+				};
+				let control2 = offset(parser.point()?);
+				let to = offset(parser.point()?);
+				control = Some(control2);
+				flatten_cubic(current, control1, control2, to, segments)
+			},
+			b'Q' | b'T' => {
+				let quadratic_control = if command.eq_ignore_ascii_case(&b'Q') {
+					offset(parser.point()?)
+				} else {
+					match (previous_command.map(|c| c.to_ascii_uppercase()), last_control) {
+						(Some(b'Q' | b'T'), Some(last)) => reflect(last, current),
+						_ => current,
+					}
+				};
+				let to = offset(parser.point()?);
+				control = Some(quadratic_control);
+				flatten_quadratic(current, quadratic_control, to, segments)
+			},
+			b'A' => {
+				let radii = [parser.number()?, parser.number()?];
+				let rotation = parser.number()?;
+				let large_arc = parser.flag()?;
+				let sweep = parser.flag()?;
+				let to = offset(parser.point()?);
+				flatten_arc(current, radii, rotation, large_arc, sweep, to, segments)
+			},
+			_ => return Err(SvgError::InvalidPath),
+		};
+
+		if let Some(&last) = points.last() { current = last; }
+		polyline.extend(points);
+		last_control = control;
+		previous_command = Some(command);
+	}
+
+	if polyline.len() > 1 { polylines.push(polyline); }
+	return Ok(polylines);
+}
\ No newline at end of file
@@ -0,0 +1,66 @@
+use super::error::SvgError;
+use super::path::parse_numbers;
+
+// The SVG matrix(a, b, c, d, e, f), mapping (x, y) to (a x + c y + e, b x + d y + f)
+#[derive(Clone, Copy, Debug)]
+pub struct Transform(pub [f64; 6]);
+
+impl Default for Transform {
+	fn default() -> Self {
+		return Self([1.0, 0.0, 0.0, 1.0, 0.0, 0.0]);
+	}
+}
+
+impl Transform {
+	pub fn new() -> Self {
+		return Self { ..Default::default() }
+	}
+
+	pub fn multiply(&self, other: &Self) -> Self {
+		let [a1, b1, c1, d1, e1, f1] = self.0;
+		let [a2, b2, c2, d2, e2, f2] = other.0;
+		return Self([
+			a1 * a2 + c1 * b2,
+			b1 * a2 + d1 * b2,
+			a1 * c2 + c1 * d2,
+			b1 * c2 + d1 * d2,
+			a1 * e2 + c1 * f2 + e1,
+			b1 * e2 + d1 * f2 + f1,
+		]);
+	}
+
+	pub fn apply(&self, point: [f64; 2]) -> [f64; 2] {
+		let [a, b, c, d, e, f] = self.0;
+		return [a * point[0] + c * point[1] + e, b * point[0] + d * point[1] + f];
+	}
+}
+
+pub fn parse_transform(transform: &str) -> Result<Transform, SvgError> {
+	let mut result = Transform::new();
+	for function in transform.split(')') {
+		let function = function.trim().trim_start_matches(',').trim();
+		if function.is_empty() { continue; }
+		let (name, arguments) = function.split_once('(').ok_or(SvgError::InvalidTransform)?;
+		let values = parse_numbers(arguments)?;
+		let value = |index: usize| values.get(index).copied().unwrap_or(0.0);
+
+		let matrix = match (name.trim(), values.len()) {
+			("matrix", 6) => [value(0), value(1), value(2), value(3), value(4), value(5)],
+			("translate", 1 | 2) => [1.0, 0.0, 0.0, 1.0, value(0), value(1)],
+			("scale", 1) => [value(0), 0.0, 0.0, value(0), 0.0, 0.0],
+			("scale", 2) => [value(0), 0.0, 0.0, value(1), 0.0, 0.0],
+			("rotate", 1 | 3) => {
+				let (sin, cos) = value(0).to_radians().sin_cos();
+				let rotation = Transform([cos, sin, -sin, cos, 0.0, 0.0]);
+				let to_center = Transform([1.0, 0.0, 0.0, 1.0, value(1), value(2)]);
+				let from_center = Transform([1.0, 0.0, 0.0, 1.0, -value(1), -value(2)]);
+				to_center.multiply(&rotation).multiply(&from_center).0
+			},
+			("skewX", 1) => [1.0, 0.0, value(0).to_radians().tan(), 1.0, 0.0, 0.0],
+			("skewY", 1) => [1.0, value(0).to_radians().tan(), 0.0, 1.0, 0.0, 0.0],
+			_ => return Err(SvgError::InvalidTransform),
+		};
+		result = result.multiply(&Transform(matrix));
+	}
+	return Ok(result);
+}
\ No newline at end of file
@@ -0,0 +1,150 @@
+use crate::real::Real;
+use super::point::Point;
+use super::line::Line;
+
+fn real(value: f64) -> Real {
+	return Real::from_f64(value);
+}
+
+fn solve_quadratic(a: &Real, b: &Real, c: &Real) -> Vec<Real> {
+	if *a == 0.0 {
+		if *b == 0.0 { return Vec::new(); }
+		return vec![-(c / b)];
+	}
+	let discriminant = b * b - real(4.0) * a * c;
+	if discriminant < 0.0 { return Vec::new(); }
+	if discriminant == 0.0 { return vec![-(b / (real(2.0) * a))]; }
+	let root = discriminant.sqrt();
+	return vec![(-b + &root) / (real(2.0) * a), (-b - &root) / (real(2.0) * a)];
+}
+
+pub fn solve_cubic(a: &Real, b: &Real, c: &Real, d: &Real) -> Vec<Real> {
+	if *a == 0.0 { return solve_quadratic(b, c, d); }
+
+	let b = b / a;
+	let c = c / a;
+	let d = d / a;
+	let shift = -(&b / real(3.0));
+	let p = &c - &b * &b / real(3.0);
+	let q = real(2.0) * &b * &b * &b / real(27.0) - &b * &c / real(3.0) + &d;
+
+	let half_q = &q / real(2.0);
+	let third_p = &p / real(3.0);
+	let discriminant = &half_q * &half_q + &third_p * &third_p * &third_p;
+
+	let mut roots: Vec<Real> = Vec::new();
+	if discriminant > 0.0 {
+		let root = discriminant.sqrt();
+		roots.push((-&half_q + &root).cbrt() + (-&half_q - &root).cbrt());
+	} else if discriminant == 0.0 {
+		if p == 0.0 {
+			roots.push(real(0.0));
+		} else {
+			roots.push(real(3.0) * &q / &p);
+			roots.push(-(real(3.0) * &q / (real(2.0) * &p)));
+		}
+	} else {
+		let radius = real(2.0) * (-&third_p).sqrt();
+		let mut cosine = real(3.0) * &q / (real(2.0) * &p) * (real(-3.0) / &p).sqrt();
+		if cosine > 1.0 { cosine = real(1.0); }
+		if cosine < -1.0 { cosine = real(-1.0); }
+		let angle = cosine.acos() / real(3.0);
+		for k in 0..3 {
+			let offset = real(2.0) * Real::pi() * real(k as f64) / real(3.0);
+			roots.push(&radius * (&angle - &offset).cos());
+		}
+	}
+
+	return roots.into_iter().map(|root| root + &shift).collect();
+}
+
+fn perpendicular_bisector(a: &Point, b: &Point) -> Option<Line> {
+	if a == b { return None; }
+	return Some(Line::new(a.midpoint(b), (b - a).perpendicular()));
+}
+
+pub fn axiom1(point1: &Point, point2: &Point) -> Vec<Line> {
+	if point1 == point2 { return Vec::new(); }
+	return vec![Line::through(point1, point2)];
+}
+
+pub fn axiom2(point1: &Point, point2: &Point) -> Vec<Line> {
+	return perpendicular_bisector(point1, point2).into_iter().collect();
+}
+
+pub fn axiom3(line1: &Line, line2: &Line) -> Vec<Line> {
+	if line1.vector.is_zero() || line2.vector.is_zero() { return Vec::new(); }
+	if line1.is_parallel(line2) {
+		if line1.side(&line2.origin) == 0.0 { return Vec::new(); }
+		return vec![Line::new(line1.origin.midpoint(&line2.origin), line1.vector.clone())];
+	}
+
+	let origin = line1.intersection(line2).unwrap();
+	let vector1 = line1.vector.normalize();
+	let vector2 = line2.vector.normalize();
+	return vec![
+		Line::new(origin.clone(), &vector1 + &vector2),
+		Line::new(origin, &vector1 - &vector2),
+	];
+}
+
+pub fn axiom4(point1: &Point, line1: &Line) -> Vec<Line> {
+	if line1.vector.is_zero() { return Vec::new(); }
+	return vec![Line::new(point1.clone(), line1.vector.perpendicular())];
+}
+
+pub fn axiom5(point1: &Point, point2: &Point, line1: &Line) -> Vec<Line> {
+	if line1.vector.is_zero() { return Vec::new(); }
+	let offset = &line1.origin - point2;
+	let radius_squared = (point1 - point2).length_squared();
+	let a = line1.vector.length_squared();
+	let b = real(2.0) * line1.vector.dot(&offset);
+	let c = offset.length_squared() - radius_squared;
+
+	let mut lines: Vec<Line> = Vec::new();
+	for parameter in solve_quadratic(&a, &b, &c) {
+		let image = line1.point_at(&parameter);
+		lines.extend(perpendicular_bisector(point1, &image));
+	}
+	return lines;
+}
+
+pub fn axiom6(point1: &Point, line1: &Line, point2: &Point, line2: &Line) -> Vec<Line> {
+	if line1.vector.is_zero() || line2.vector.is_zero() { return Vec::new(); }
+
+	// The image of point1 is line1.origin + t * line1.vector, which fixes the crease
+	// as the perpendicular bisector; reflecting point2 onto line2 gives a cubic in t.
+	let a = &line1.origin - point1;
+	let b = point2 - point1;
+	let u = &line1.vector;
+	let normal = line2.vector.perpendicular();
+	let distance = (point2 - &line2.origin).dot(&normal);
+
+	let a0 = a.dot(&a);
+	let a1 = real(2.0) * a.dot(u);
+	let a2 = u.dot(u);
+	let b0 = b.dot(&a);
+	let b1 = b.dot(u);
+	let c0 = a.dot(&normal);
+	let c1 = u.dot(&normal);
+
+	let cubic = &a2 * &c1;
+	let quadratic = &distance * &a2 - real(2.0) * &b1 * &c1 + &a1 * &c1 + &a2 * &c0;
+	let linear = &distance * &a1 - real(2.0) * (&b0 * &c1 + &b1 * &c0) + &a0 * &c1 + &a1 * &c0;
+	let constant = &distance * &a0 - real(2.0) * &b0 * &c0 + &a0 * &c0;
+
+	let mut lines: Vec<Line> = Vec::new();
+	for parameter in solve_cubic(&cubic, &quadratic, &linear, &constant) {
+		let image = line1.point_at(&parameter);
+		lines.extend(perpendicular_bisector(point1, &image));
+	}
+	return lines;
+}
+
+pub fn axiom7(point1: &Point, line1: &Line, line2: &Line) -> Vec<Line> {
+	let denominator = line1.vector.cross(&line2.vector);
+	if denominator == 0.0 { return Vec::new(); }
+	let parameter = -(line1.vector.cross(&(point1 - &line1.origin)) / denominator);
+	let image = point1 + &line2.vector.scale(&parameter);
+	return perpendicular_bisector(point1, &image).into_iter().collect();
+}
\ No newline at end of file
@@ -0,0 +1,49 @@
+use crate::real::Real;
+use super::point::Point;
+
+#[derive(Clone, Debug)]
+pub struct Line {
+	pub origin: Point,
+	pub vector: Point,
+}
+
+impl Line {
+	pub fn new(origin: Point, vector: Point) -> Self {
+		return Self { origin, vector }
+	}
+
+	pub fn through(a: &Point, b: &Point) -> Self {
+		return Self { origin: a.clone(), vector: b - a }
+	}
+
+	pub fn point_at(&self, parameter: &Real) -> Point {
+		return &self.origin + &self.vector.scale(parameter);
+	}
+
+	pub fn parameter(&self, point: &Point) -> Real {
+		return (point - &self.origin).dot(&self.vector) / self.vector.length_squared();
+	}
+
+	pub fn side(&self, point: &Point) -> Real {
+		return self.vector.cross(&(point - &self.origin));
+	}
+
+	pub fn is_parallel(&self, other: &Self) -> bool {
+		return self.vector.cross(&other.vector) == 0.0;
+	}
+
+	pub fn intersection_parameter(&self, other: &Self) -> Option<Real> {
+		let denominator = self.vector.cross(&other.vector);
+		if denominator == 0.0 { return None; }
+		return Some((&other.origin - &self.origin).cross(&other.vector) / denominator);
+	}
+
+	pub fn intersection(&self, other: &Self) -> Option<Point> {
+		return self.intersection_parameter(other).map(|parameter| self.point_at(&parameter));
+	}
+
+	pub fn reflect(&self, point: &Point) -> Point {
+		let foot = self.point_at(&self.parameter(point));
+		return &(&foot + &foot) - point;
+	}
+}
\ No newline at end of file
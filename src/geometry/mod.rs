@@ -0,0 +1,22 @@
+pub mod predicates;
+
+mod point;
+mod line;
+mod polygon;
+mod axioms;
+
+pub use point::Point;
+pub use line::Line;
+
+pub use polygon::segment_contains;
+pub use polygon::polygon_area;
+pub use polygon::polygon_contains;
+
+pub use axioms::solve_cubic;
+pub use axioms::axiom1;
+pub use axioms::axiom2;
+pub use axioms::axiom3;
+pub use axioms::axiom4;
+pub use axioms::axiom5;
+pub use axioms::axiom6;
+pub use axioms::axiom7;
\ No newline at end of file
@@ -0,0 +1,80 @@
+use core::ops::{Add, Sub};
+
+use crate::real::Real;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Point {
+	pub x: Real,
+	pub y: Real,
+}
+
+impl Point {
+	pub fn new(x: Real, y: Real) -> Self {
+		return Self { x, y }
+	}
+
+	pub fn from_f64(x: f64, y: f64) -> Self {
+		return Self { x: Real::from_f64(x), y: Real::from_f64(y) }
+	}
+
+	pub fn from_coordinates(coordinates: &[Real]) -> Self {
+		let x = coordinates.first().cloned().unwrap_or(Real::from_f64(0.0));
+		let y = coordinates.get(1).cloned().unwrap_or(Real::from_f64(0.0));
+		return Self { x, y }
+	}
+
+	pub fn to_coordinates(&self) -> Vec<Real> {
+		return vec![self.x.clone(), self.y.clone()];
+	}
+
+	pub fn scale(&self, factor: &Real) -> Self {
+		return Self { x: &self.x * factor, y: &self.y * factor }
+	}
+
+	pub fn dot(&self, other: &Self) -> Real {
+		return &self.x * &other.x + &self.y * &other.y;
+	}
+
+	pub fn cross(&self, other: &Self) -> Real {
+		return &self.x * &other.y - &self.y * &other.x;
+	}
+
+	pub fn perpendicular(&self) -> Self {
+		return Self { x: -&self.y, y: self.x.clone() }
+	}
+
+	pub fn midpoint(&self, other: &Self) -> Self {
+		return (self + other).scale(&Real::from_f64(0.5));
+	}
+
+	pub fn length_squared(&self) -> Real {
+		return self.dot(self);
+	}
+
+	pub fn length(&self) -> Real {
+		return self.length_squared().sqrt();
+	}
+
+	pub fn normalize(&self) -> Self {
+		let length = self.length();
+		return Self { x: &self.x / &length, y: &self.y / &length }
+	}
+
+	pub fn is_zero(&self) -> bool {
+		return self.x == 0.0 && self.y == 0.0;
+	}
+}
+
+impl Add<&Point> for &Point {
+	type Output = Point;
+	fn add(self, other: &Point) -> Point {
+		return Point { x: &self.x + &other.x, y: &self.y + &other.y };
+	}
+}
+
+impl Sub<&Point> for &Point {
+	type Output = Point;
+	fn sub(self, other: &Point) -> Point {
+		return Point { x: &self.x - &other.x, y: &self.y - &other.y };
+	}
+}
\ No newline at end of file
@@ -0,0 +1,31 @@
+use crate::real::Real;
+use super::point::Point;
+use super::predicates::{Sign, SegmentLocation, orient2d, point_on_segment};
+
+pub fn segment_contains(a: &Point, b: &Point, point: &Point) -> bool {
+	return point_on_segment(a, b, point) != SegmentLocation::Outside;
+}
+
+pub fn polygon_area(polygon: &[Point]) -> Real {
+	let d = polygon.len();
+	let mut area = Real::from_f64(0.0);
+	for index in 0..d {
+		area = area + polygon[index].cross(&polygon[(index + 1) % d]);
+	}
+	return area / Real::from_f64(2.0);
+}
+
+pub fn polygon_contains(polygon: &[Point], point: &Point) -> bool {
+	let d = polygon.len();
+	let mut inside = false;
+	for index in 0..d {
+		let a = &polygon[index];
+		let b = &polygon[(index + 1) % d];
+		if segment_contains(a, b, point) { return false; }
+		if (a.y > point.y) != (b.y > point.y) {
+			let orientation = if b.y > a.y { Sign::Positive } else { Sign::Negative };
+			if orient2d(a, b, point) == orientation { inside = !inside; }
+		}
+	}
+	return inside;
+}
\ No newline at end of file
@@ -0,0 +1,64 @@
+use crate::real::Real;
+
+#[cfg(not(feature = "real_is_rug"))]
+use super::expansion::Expansion;
+
+#[cfg(feature = "real_is_rug")]
+use rug::Rational;
+
+#[cfg(not(feature = "real_is_rug"))]
+pub type Exact = Expansion;
+
+#[cfg(feature = "real_is_rug")]
+pub type Exact = Rational;
+
+#[cfg(not(feature = "real_is_rug"))]
+pub fn exact(value: &Real) -> Exact {
+	return Expansion::from_f64(value.to_f64());
+}
+
+#[cfg(feature = "real_is_rug")]
+pub fn exact(value: &Real) -> Exact {
+	return value.to_rational();
+}
+
+#[cfg(not(feature = "real_is_rug"))]
+pub fn signum(value: &Exact) -> i8 {
+	return value.signum();
+}
+
+#[cfg(feature = "real_is_rug")]
+pub fn signum(value: &Exact) -> i8 {
+	return value.cmp0() as i8;
+}
+
+pub fn add(a: &Exact, b: &Exact) -> Exact {
+	return Exact::from(a + b);
+}
+
+pub fn sub(a: &Exact, b: &Exact) -> Exact {
+	return Exact::from(a - b);
+}
+
+pub fn mul(a: &Exact, b: &Exact) -> Exact {
+	return Exact::from(a * b);
+}
+
+pub fn determinant2(a: &Exact, b: &Exact, c: &Exact, d: &Exact) -> Exact {
+	return sub(&mul(a, d), &mul(b, c));
+}
+
+pub fn determinant3(rows: &[[Exact; 3]; 3]) -> Exact {
+	let minor0 = determinant2(&rows[1][1], &rows[1][2], &rows[2][1], &rows[2][2]);
+	let minor1 = determinant2(&rows[1][0], &rows[1][2], &rows[2][0], &rows[2][2]);
+	let minor2 = determinant2(&rows[1][0], &rows[1][1], &rows[2][0], &rows[2][1]);
+	let sum = sub(&mul(&rows[0][0], &minor0), &mul(&rows[0][1], &minor1));
+	return add(&sum, &mul(&rows[0][2], &minor2));
+}
+
+// Returns the value when it is exactly representable as f64
+pub fn exact_f64(value: &Real) -> Option<f64> {
+	let result = value.to_f64();
+	if *value == result && result.is_finite() { return Some(result); }
+	return None;
+}
\ No newline at end of file
@@ -0,0 +1,74 @@
+use core::ops::{Add, Sub, Mul};
+
+// Nonoverlapping components sorted by increasing magnitude, zeros eliminated
+#[derive(Clone, Debug, Default)]
+pub struct Expansion(Vec<f64>);
+
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+	let sum = a + b;
+	let b_virtual = sum - a;
+	let a_virtual = sum - b_virtual;
+	return (sum, (a - a_virtual) + (b - b_virtual));
+}
+
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+	let product = a * b;
+	return (product, a.mul_add(b, -product));
+}
+
+impl Expansion {
+	pub fn from_f64(value: f64) -> Self {
+		if value == 0.0 { return Self(Vec::new()); }
+		return Self(vec![value]);
+	}
+
+	fn grow(&self, value: f64) -> Self {
+		let mut components: Vec<f64> = Vec::with_capacity(self.0.len() + 1);
+		let mut sum = value;
+		for &component in &self.0 {
+			let (next, error) = two_sum(sum, component);
+			if error != 0.0 { components.push(error); }
+			sum = next;
+		}
+		if sum != 0.0 { components.push(sum); }
+		return Self(components);
+	}
+
+	fn scale(&self, factor: f64) -> Self {
+		let mut result = Self::default();
+		for &component in &self.0 {
+			let (product, error) = two_product(component, factor);
+			result = result.grow(error).grow(product);
+		}
+		return result;
+	}
+
+	pub fn signum(&self) -> i8 {
+		return match self.0.last() {
+			Some(component) if *component > 0.0 => 1,
+			Some(component) if *component < 0.0 => -1,
+			_ => 0,
+		};
+	}
+}
+
+impl Add<&Expansion> for &Expansion {
+	type Output = Expansion;
+	fn add(self, other: &Expansion) -> Expansion {
+		return other.0.iter().fold(self.clone(), |sum, &component| sum.grow(component));
+	}
+}
+
+impl Sub<&Expansion> for &Expansion {
+	type Output = Expansion;
+	fn sub(self, other: &Expansion) -> Expansion {
+		return other.0.iter().fold(self.clone(), |sum, &component| sum.grow(-component));
+	}
+}
+
+impl Mul<&Expansion> for &Expansion {
+	type Output = Expansion;
+	fn mul(self, other: &Expansion) -> Expansion {
+		return other.0.iter().fold(Expansion::default(), |sum, &component| &sum + &self.scale(component));
+	}
+}
\ No newline at end of file
@@ -0,0 +1,16 @@
+mod exact;
+#[cfg(not(feature = "real_is_rug"))]
+mod expansion;
+mod orientation;
+mod segment;
+
+pub use orientation::Sign;
+pub use segment::SegmentLocation;
+pub use segment::SegmentIntersection;
+
+pub use orientation::orient2d;
+pub use orientation::orient3d;
+pub use orientation::incircle;
+
+pub use segment::point_on_segment;
+pub use segment::segment_intersection;
\ No newline at end of file
@@ -0,0 +1,121 @@
+use crate::real::Real;
+use crate::geometry::Point;
+use super::exact::{Exact, exact, exact_f64, signum, sub, mul, add, determinant2, determinant3};
+
+const EPSILON: f64 = f64::EPSILON / 2.0;
+const ORIENT2D_BOUND: f64 = (3.0 + 16.0 * EPSILON) * EPSILON;
+const ORIENT3D_BOUND: f64 = (7.0 + 56.0 * EPSILON) * EPSILON;
+const INCIRCLE_BOUND: f64 = (10.0 + 96.0 * EPSILON) * EPSILON;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Sign {
+	Negative,
+	Zero,
+	Positive,
+}
+
+impl Sign {
+	fn from_signum(signum: i8) -> Self {
+		return match signum {
+			0 => Sign::Zero,
+			s if s > 0 => Sign::Positive,
+			_ => Sign::Negative,
+		};
+	}
+
+	fn from_filter(determinant: f64, bound: f64) -> Option<Self> {
+		if determinant > bound { return Some(Sign::Positive); }
+		if -determinant > bound { return Some(Sign::Negative); }
+		return None;
+	}
+}
+
+fn exact_f64_all<const N: usize>(values: [&Real; N]) -> Option<[f64; N]> {
+	let mut result = [0.0; N];
+	for (index, value) in values.iter().enumerate() {
+		result[index] = exact_f64(value)?;
+	}
+	return Some(result);
+}
+
+fn coordinate(point: &[Real], index: usize) -> Real {
+	return point.get(index).cloned().unwrap_or(Real::from_f64(0.0));
+}
+
+fn orient2d_filter(a: &Point, b: &Point, c: &Point) -> Option<Sign> {
+	let [ax, ay, bx, by, cx, cy] = exact_f64_all([&a.x, &a.y, &b.x, &b.y, &c.x, &c.y])?;
+	let left = (ax - cx) * (by - cy);
+	let right = (ay - cy) * (bx - cx);
+	return Sign::from_filter(left - right, ORIENT2D_BOUND * (left.abs() + right.abs()));
+}
+
+// Positive when a, b and c are in counter-clockwise order
+pub fn orient2d(a: &Point, b: &Point, c: &Point) -> Sign {
+	if let Some(sign) = orient2d_filter(a, b, c) { return sign; }
+	let (cx, cy) = (exact(&c.x), exact(&c.y));
+	let (acx, acy) = (sub(&exact(&a.x), &cx), sub(&exact(&a.y), &cy));
+	let (bcx, bcy) = (sub(&exact(&b.x), &cx), sub(&exact(&b.y), &cy));
+	return Sign::from_signum(signum(&determinant2(&acx, &acy, &bcx, &bcy)));
+}
+
+fn orient3d_filter(a: &[Real], b: &[Real], c: &[Real], d: &[Real]) -> Option<Sign> {
+	let coordinates: Vec<Real> = [a, b, c, d].iter()
+		.flat_map(|point| (0..3).map(|index| coordinate(point, index)))
+		.collect();
+	let values: [&Real; 12] = core::array::from_fn(|index| &coordinates[index]);
+	let [ax, ay, az, bx, by, bz, cx, cy, cz, dx, dy, dz] = exact_f64_all(values)?;
+	let (adx, ady, adz) = (ax - dx, ay - dy, az - dz);
+	let (bdx, bdy, bdz) = (bx - dx, by - dy, bz - dz);
+	let (cdx, cdy, cdz) = (cx - dx, cy - dy, cz - dz);
+
+	let (bdxcdy, cdxbdy) = (bdx * cdy, cdx * bdy);
+	let (cdxady, adxcdy) = (cdx * ady, adx * cdy);
+	let (adxbdy, bdxady) = (adx * bdy, bdx * ady);
+	let determinant = adz * (bdxcdy - cdxbdy) + bdz * (cdxady - adxcdy) + cdz * (adxbdy - bdxady);
+	let permanent = (bdxcdy.abs() + cdxbdy.abs()) * adz.abs()
+		+ (cdxady.abs() + adxcdy.abs()) * bdz.abs()
+		+ (adxbdy.abs() + bdxady.abs()) * cdz.abs();
+	return Sign::from_filter(determinant, ORIENT3D_BOUND * permanent);
+}
+
+// Positive when d lies below the plane of a, b and c seen counter-clockwise from above
+pub fn orient3d(a: &[Real], b: &[Real], c: &[Real], d: &[Real]) -> Sign {
+	if let Some(sign) = orient3d_filter(a, b, c, d) { return sign; }
+	let origin: [Exact; 3] = core::array::from_fn(|index| exact(&coordinate(d, index)));
+	let row = |point: &[Real]| -> [Exact; 3] {
+		return core::array::from_fn(|index| sub(&exact(&coordinate(point, index)), &origin[index]));
+	};
+	return Sign::from_signum(signum(&determinant3(&[row(a), row(b), row(c)])));
+}
+
+fn incircle_filter(a: &Point, b: &Point, c: &Point, d: &Point) -> Option<Sign> {
+	let [ax, ay, bx, by, cx, cy, dx, dy] = exact_f64_all([&a.x, &a.y, &b.x, &b.y, &c.x, &c.y, &d.x, &d.y])?;
+	let (adx, ady) = (ax - dx, ay - dy);
+	let (bdx, bdy) = (bx - dx, by - dy);
+	let (cdx, cdy) = (cx - dx, cy - dy);
+
+	let (bdxcdy, cdxbdy) = (bdx * cdy, cdx * bdy);
+	let (cdxady, adxcdy) = (cdx * ady, adx * cdy);
+	let (adxbdy, bdxady) = (adx * bdy, bdx * ady);
+	let alift = adx * adx + ady * ady;
+	let blift = bdx * bdx + bdy * bdy;
+	let clift = cdx * cdx + cdy * cdy;
+	let determinant = alift * (bdxcdy - cdxbdy) + blift * (cdxady - adxcdy) + clift * (adxbdy - bdxady);
+	let permanent = (bdxcdy.abs() + cdxbdy.abs()) * alift
+		+ (cdxady.abs() + adxcdy.abs()) * blift
+		+ (adxbdy.abs() + bdxady.abs()) * clift;
+	return Sign::from_filter(determinant, INCIRCLE_BOUND * permanent);
+}
+
+// Positive when d lies inside the circle through counter-clockwise a, b and c
+pub fn incircle(a: &Point, b: &Point, c: &Point, d: &Point) -> Sign {
+	if let Some(sign) = incircle_filter(a, b, c, d) { return sign; }
+	let (dx, dy) = (exact(&d.x), exact(&d.y));
+	let row = |point: &Point| -> [Exact; 3] {
+		let x = sub(&exact(&point.x), &dx);
+		let y = sub(&exact(&point.y), &dy);
+		let lift = add(&mul(&x, &x), &mul(&y, &y));
+		return [x, y, lift];
+	};
+	return Sign::from_signum(signum(&determinant3(&[row(a), row(b), row(c)])));
+}
\ No newline at end of file
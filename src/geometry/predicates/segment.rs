@@ -0,0 +1,73 @@
+use crate::real::Real;
+use crate::geometry::Point;
+use super::orientation::{Sign, orient2d};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SegmentLocation {
+	Outside,
+	Endpoint,
+	Interior,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SegmentIntersection {
+	Disjoint,
+	Touching,
+	Proper,
+	Overlapping,
+}
+
+// Collinear points are ordered along x unless the segment is vertical
+fn projection<'a>(a: &Point, b: &Point, point: &'a Point) -> &'a Real {
+	if a.x == b.x { return &point.y; }
+	return &point.x;
+}
+
+fn is_strictly_between(a: &Real, b: &Real, value: &Real) -> bool {
+	return (a < value && value < b) || (b < value && value < a);
+}
+
+pub fn point_on_segment(a: &Point, b: &Point, point: &Point) -> SegmentLocation {
+	if point == a || point == b { return SegmentLocation::Endpoint; }
+	if a == b || orient2d(a, b, point) != Sign::Zero { return SegmentLocation::Outside; }
+	if is_strictly_between(projection(a, b, a), projection(a, b, b), projection(a, b, point)) {
+		return SegmentLocation::Interior;
+	}
+	return SegmentLocation::Outside;
+}
+
+fn is_on_segment(a: &Point, b: &Point, point: &Point) -> bool {
+	return point_on_segment(a, b, point) != SegmentLocation::Outside;
+}
+
+fn collinear_intersection(a: &Point, b: &Point, c: &Point, d: &Point) -> SegmentIntersection {
+	let (a, b, c, d) = (projection(a, b, a), projection(a, b, b), projection(a, b, c), projection(a, b, d));
+	let (min1, max1) = if a < b { (a, b) } else { (b, a) };
+	let (min2, max2) = if c < d { (c, d) } else { (d, c) };
+	let start = if min1 > min2 { min1 } else { min2 };
+	let end = if max1 < max2 { max1 } else { max2 };
+	if start < end { return SegmentIntersection::Overlapping; }
+	if start == end { return SegmentIntersection::Touching; }
+	return SegmentIntersection::Disjoint;
+}
+
+pub fn segment_intersection(a: &Point, b: &Point, c: &Point, d: &Point) -> SegmentIntersection {
+	if a == b { return if is_on_segment(c, d, a) { SegmentIntersection::Touching } else { SegmentIntersection::Disjoint }; }
+	if c == d { return if is_on_segment(a, b, c) { SegmentIntersection::Touching } else { SegmentIntersection::Disjoint }; }
+
+	let orientation_c = orient2d(a, b, c);
+	let orientation_d = orient2d(a, b, d);
+	if orientation_c == Sign::Zero && orientation_d == Sign::Zero {
+		return collinear_intersection(a, b, c, d);
+	}
+
+	let orientation_a = orient2d(c, d, a);
+	let orientation_b = orient2d(c, d, b);
+	if orientation_c == orientation_d || orientation_a == orientation_b {
+		return SegmentIntersection::Disjoint;
+	}
+	let is_touching = orientation_a == Sign::Zero || orientation_b == Sign::Zero
+		|| orientation_c == Sign::Zero || orientation_d == Sign::Zero;
+	if is_touching { return SegmentIntersection::Touching; }
+	return SegmentIntersection::Proper;
+}
\ No newline at end of file
@@ -0,0 +1,66 @@
+use std::collections::HashSet;
+
+use crate::fold::{Graph, EdgeAssignment};
+use crate::geometry::{Line, Point, polygon_contains};
+use super::split::{vertex_point, add_vertex, split_edge, split_face, rebuild_topology};
+
+fn find_face_chord(graph: &Graph, face_index: usize, line: &Line, line_vertices: &HashSet<usize>) -> Option<(usize, usize)> {
+	let face_vertices = &graph.faces_vertices[face_index];
+	let d = face_vertices.len();
+	let mut positions: Vec<usize> = (0..d).filter(|&index| line_vertices.contains(&face_vertices[index])).collect();
+	if positions.len() < 2 { return None; }
+
+	let polygon: Vec<Point> = face_vertices.iter().map(|&v| vertex_point(graph, v)).collect();
+	positions.sort_by(|&a, &b| {
+		let parameter_a = line.parameter(&polygon[a]);
+		let parameter_b = line.parameter(&polygon[b]);
+		parameter_a.partial_cmp(&parameter_b).unwrap()
+	});
+
+	for pair in positions.windows(2) {
+		let (a, b) = (pair[0], pair[1]);
+		if (a + 1) % d == b || (b + 1) % d == a { continue; }
+		if polygon[a] == polygon[b] { continue; }
+		if polygon_contains(&polygon, &polygon[a].midpoint(&polygon[b])) {
+			return Some((face_vertices[a], face_vertices[b]));
+		}
+	}
+	return None;
+}
+
+pub fn insert_line(graph: &mut Graph, line: &Line, assignment: EdgeAssignment) -> Vec<usize> {
+	let mut new_edges: Vec<usize> = Vec::new();
+	if line.vector.is_zero() { return new_edges; }
+
+	let mut line_vertices: HashSet<usize> = HashSet::new();
+	for vertex_index in 0..graph.vertices_coordinates.len() {
+		if line.side(&vertex_point(graph, vertex_index)) == 0.0 { line_vertices.insert(vertex_index); }
+	}
+
+	for edge_index in 0..graph.edges_vertices.len() {
+		let a = vertex_point(graph, graph.edges_vertices[edge_index][0]);
+		let b = vertex_point(graph, graph.edges_vertices[edge_index][1]);
+		let side_a = line.side(&a);
+		let side_b = line.side(&b);
+		let crosses = (side_a > 0.0 && side_b < 0.0) || (side_a < 0.0 && side_b > 0.0);
+		if !crosses { continue; }
+
+		let ratio = &side_a / (&side_a - &side_b);
+		let point = &a + &(&b - &a).scale(&ratio);
+		let vertex_index = add_vertex(graph, &point);
+		split_edge(graph, edge_index, vertex_index);
+		line_vertices.insert(vertex_index);
+	}
+
+	let mut face_index = 0;
+	while face_index < graph.faces_vertices.len() {
+		let chord = find_face_chord(graph, face_index, line, &line_vertices);
+		match chord.and_then(|(a, b)| split_face(graph, face_index, a, b, assignment)) {
+			Some((edge_index, _)) => new_edges.push(edge_index),
+			None => face_index += 1,
+		}
+	}
+
+	rebuild_topology(graph);
+	return new_edges;
+}
\ No newline at end of file
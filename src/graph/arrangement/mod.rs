@@ -0,0 +1,13 @@
+mod split;
+mod line;
+mod segment;
+mod planarize;
+
+pub use split::assignment_fold_angle;
+pub use split::split_edge;
+pub use split::split_face;
+pub use split::rebuild_topology;
+
+pub use line::insert_line;
+pub use segment::insert_segment;
+pub use planarize::planarize;
\ No newline at end of file
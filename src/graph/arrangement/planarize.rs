@@ -0,0 +1,44 @@
+use crate::fold::{Graph, EdgeAssignment};
+use crate::graph::make::make_faces_vertices;
+use crate::graph::spatial::SpatialIndex;
+use super::split::{vertex_point, rebuild_topology, assignment_fold_angle};
+use super::segment::{insert_segment_with_fold_angle, bounding_size, merge_tolerance};
+
+// Segments only test the elements of the cells they cross, sized for about one source edge per cell
+pub fn planarize(graph: &mut Graph) {
+	let mut planar_graph = Graph::new();
+	let size = bounding_size(graph, &[]);
+	let tolerance = merge_tolerance(size);
+	let cell_size = size / (graph.edges_vertices.len().max(1) as f64).sqrt();
+	let mut index = SpatialIndex::with_cell_size(&planar_graph, cell_size);
+	for (edge_index, edge_vertices) in graph.edges_vertices.iter().enumerate() {
+		let a = vertex_point(graph, edge_vertices[0]);
+		let b = vertex_point(graph, edge_vertices[1]);
+		let assignment = graph.edges_assignment.get(edge_index).copied().unwrap_or(EdgeAssignment::Unknown);
+		let fold_angle = match graph.edges_fold_angle.get(edge_index) {
+			Some(fold_angle) => fold_angle.clone(),
+			None => assignment_fold_angle(assignment),
+		};
+		insert_segment_with_fold_angle(&mut planar_graph, &mut index, tolerance, &a, &b, assignment, fold_angle);
+	}
+
+	if graph.edges_assignment.len() == 0 { planar_graph.edges_assignment.clear(); }
+	if graph.edges_fold_angle.len() == 0 { planar_graph.edges_fold_angle.clear(); }
+	if graph.edges_length.len() > 0 {
+		planar_graph.edges_length = planar_graph.edges_vertices.iter()
+			.map(|edge_vertices| (&vertex_point(&planar_graph, edge_vertices[1]) - &vertex_point(&planar_graph, edge_vertices[0])).length())
+			.collect();
+	}
+	planar_graph.faces_vertices = make_faces_vertices(&planar_graph);
+
+	graph.vertices_coordinates = planar_graph.vertices_coordinates;
+	graph.edges_vertices = planar_graph.edges_vertices;
+	graph.edges_assignment = planar_graph.edges_assignment;
+	graph.edges_fold_angle = planar_graph.edges_fold_angle;
+	graph.edges_length = planar_graph.edges_length;
+	graph.faces_vertices = planar_graph.faces_vertices;
+	graph.edge_orders.clear();
+	graph.face_orders.clear();
+
+	rebuild_topology(graph);
+}
\ No newline at end of file
@@ -0,0 +1,202 @@
+use std::collections::HashMap;
+
+use crate::fold::{Graph, EdgeAssignment};
+use crate::geometry::{Point, Line, polygon_contains};
+use crate::geometry::predicates::{SegmentLocation, SegmentIntersection, point_on_segment, segment_intersection};
+use crate::graph::spatial::SpatialIndex;
+use crate::real::Real;
+use super::split::{vertex_point, add_vertex, add_edge, split_edge, split_face, rebuild_topology, assignment_fold_angle};
+
+// Vertices closer than this fraction of the pattern size are merged
+const RELATIVE_TOLERANCE: f64 = 1e-10;
+
+// Largest side of the bounding box of the vertices and the extra points, one when it is empty
+pub fn bounding_size(graph: &Graph, points: &[&Point]) -> f64 {
+	let positions = (0..graph.vertices_coordinates.len()).map(|vertex_index| vertex_point(graph, vertex_index))
+		.chain(points.iter().map(|&point| point.clone()))
+		.map(|point| [point.x.to_f64(), point.y.to_f64()]);
+	let (mut min, mut max) = ([f64::INFINITY; 2], [f64::NEG_INFINITY; 2]);
+	for position in positions {
+		for axis in 0..2 {
+			min[axis] = min[axis].min(position[axis]);
+			max[axis] = max[axis].max(position[axis]);
+		}
+	}
+	let size = (max[0] - min[0]).max(max[1] - min[1]);
+	return if size > 0.0 && size.is_finite() { size } else { 1.0 };
+}
+
+pub fn merge_tolerance(size: f64) -> f64 {
+	return size * RELATIVE_TOLERANCE;
+}
+
+fn is_near(a: &Point, b: &Point, tolerance: f64) -> bool {
+	return (a - b).length_squared() <= Real::from_f64(tolerance * tolerance);
+}
+
+// The index keeps f64 positions, its queries get some slack and exact tests decide
+fn query_radius(tolerance: f64) -> f64 {
+	return 2.0 * tolerance;
+}
+
+fn find_vertex(graph: &Graph, index: &SpatialIndex, point: &Point, tolerance: f64) -> Option<usize> {
+	return index.vertices_within(point, query_radius(tolerance)).into_iter()
+		.find(|&vertex_index| is_near(&vertex_point(graph, vertex_index), point, tolerance));
+}
+
+fn find_edge(graph: &Graph, index: &SpatialIndex, a: usize, b: usize, tolerance: f64) -> Option<usize> {
+	return index.edges_within(&vertex_point(graph, a), query_radius(tolerance)).into_iter().find(|&edge_index| {
+		let edge_vertices = &graph.edges_vertices[edge_index];
+		return (edge_vertices[0] == a && edge_vertices[1] == b) || (edge_vertices[0] == b && edge_vertices[1] == a);
+	});
+}
+
+fn segment_parameter(a: &Point, b: &Point, point: &Point) -> Real {
+	return (point - a).dot(&(b - a)) / (b - a).length_squared();
+}
+
+// Constructed intersection points are rounded, so vertices within the tolerance also count
+fn is_on_segment(a: &Point, b: &Point, point: &Point, tolerance: f64) -> bool {
+	match point_on_segment(a, b, point) {
+		SegmentLocation::Interior => return true,
+		SegmentLocation::Endpoint => return false,
+		SegmentLocation::Outside => {},
+	}
+	if is_near(a, point, tolerance) || is_near(b, point, tolerance) { return false; }
+	let parameter = segment_parameter(a, b, point);
+	if parameter <= 0.0 || parameter >= 1.0 { return false; }
+	let distance = (b - a).cross(&(point - a)).abs() / (b - a).length();
+	return distance <= Real::from_f64(tolerance);
+}
+
+fn add_indexed_vertex(graph: &mut Graph, index: &mut SpatialIndex, point: &Point) -> usize {
+	let vertex_index = add_vertex(graph, point);
+	index.update(graph, &[vertex_index], &[], &[]);
+	return vertex_index;
+}
+
+// Faces through the edge gain the vertex without changing shape, their index entries stay valid
+fn split_indexed_edge(graph: &mut Graph, index: &mut SpatialIndex, edge_index: usize, vertex_index: usize) {
+	let new_edge_index = split_edge(graph, edge_index, vertex_index);
+	index.update(graph, &[], &[edge_index, new_edge_index], &[]);
+}
+
+// Returns the vertex at the point, splitting an edge passing through it when needed
+fn insert_vertex(graph: &mut Graph, index: &mut SpatialIndex, point: &Point, tolerance: f64) -> usize {
+	if let Some(vertex_index) = find_vertex(graph, index, point, tolerance) { return vertex_index; }
+	let vertex_index = add_indexed_vertex(graph, index, point);
+	let edge_option = index.edges_within(point, query_radius(tolerance)).into_iter().find(|&edge_index| {
+		let a = vertex_point(graph, graph.edges_vertices[edge_index][0]);
+		let b = vertex_point(graph, graph.edges_vertices[edge_index][1]);
+		return is_on_segment(&a, &b, point, tolerance);
+	});
+	if let Some(edge_index) = edge_option {
+		split_indexed_edge(graph, index, edge_index, vertex_index);
+	}
+	return vertex_index;
+}
+
+// The index must cover the graph, only elements near the segment are tested
+pub fn insert_segment_with_fold_angle(graph: &mut Graph, index: &mut SpatialIndex, tolerance: f64, a: &Point, b: &Point, assignment: EdgeAssignment, fold_angle: Real) -> Vec<usize> {
+	let mut edges: Vec<usize> = Vec::new();
+	if is_near(a, b, tolerance) { return edges; }
+
+	let vertex_a = insert_vertex(graph, index, a, tolerance);
+	let vertex_b = insert_vertex(graph, index, b, tolerance);
+	let (a, b) = (vertex_point(graph, vertex_a), vertex_point(graph, vertex_b));
+
+	let mut segment_vertices: HashMap<usize, Real> = HashMap::new();
+	segment_vertices.insert(vertex_a, Real::from_f64(0.0));
+	segment_vertices.insert(vertex_b, Real::from_f64(1.0));
+
+	for vertex_index in index.vertices_near_segment(&a, &b, query_radius(tolerance)) {
+		let point = vertex_point(graph, vertex_index);
+		if is_on_segment(&a, &b, &point, tolerance) {
+			segment_vertices.insert(vertex_index, segment_parameter(&a, &b, &point));
+		}
+	}
+
+	let segment = Line::through(&a, &b);
+	for edge_index in index.edges_near_segment(&a, &b, query_radius(tolerance)) {
+		let (u, v) = (graph.edges_vertices[edge_index][0], graph.edges_vertices[edge_index][1]);
+		if segment_vertices.contains_key(&u) || segment_vertices.contains_key(&v) { continue; }
+
+		let (point_u, point_v) = (vertex_point(graph, u), vertex_point(graph, v));
+		if segment_intersection(&a, &b, &point_u, &point_v) != SegmentIntersection::Proper { continue; }
+		let parameter = match segment.intersection_parameter(&Line::through(&point_u, &point_v)) {
+			Some(parameter) => parameter,
+			None => continue,
+		};
+
+		let point = segment.point_at(&parameter);
+		let vertex_index = match find_vertex(graph, index, &point, tolerance) {
+			Some(vertex_index) => vertex_index,
+			None => {
+				let vertex_index = add_indexed_vertex(graph, index, &point);
+				split_indexed_edge(graph, index, edge_index, vertex_index);
+				vertex_index
+			}
+		};
+		segment_vertices.insert(vertex_index, parameter);
+	}
+
+	let mut sorted_vertices: Vec<(Real, usize)> = segment_vertices.into_iter()
+		.map(|(vertex_index, parameter)| (parameter, vertex_index))
+		.collect();
+	sorted_vertices.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+	for pair in sorted_vertices.windows(2) {
+		let (p, q) = (pair[0].1, pair[1].1);
+		if p == q { continue; }
+
+		if let Some(edge_index) = find_edge(graph, index, p, q, tolerance) {
+			if graph.edges_assignment.len() > edge_index {
+				if let EdgeAssignment::Boundary = graph.edges_assignment[edge_index] {
+					edges.push(edge_index);
+					continue;
+				}
+				graph.edges_assignment[edge_index] = assignment;
+			}
+			if graph.edges_fold_angle.len() > edge_index {
+				graph.edges_fold_angle[edge_index] = fold_angle.clone();
+			}
+			edges.push(edge_index);
+			continue;
+		}
+
+		let midpoint = vertex_point(graph, p).midpoint(&vertex_point(graph, q));
+		let face_option = index.faces_near(&midpoint, query_radius(tolerance)).into_iter().find(|&face_index| {
+			let face_vertices = &graph.faces_vertices[face_index];
+			if !face_vertices.contains(&p) || !face_vertices.contains(&q) { return false; }
+			let polygon: Vec<Point> = face_vertices.iter().map(|&v| vertex_point(graph, v)).collect();
+			return polygon_contains(&polygon, &midpoint);
+		});
+
+		let split = face_option.and_then(|face_index| Some((face_index, split_face(graph, face_index, p, q, assignment)?)));
+		let edge_index = match split {
+			Some((face_index, (edge_index, new_face_index))) => {
+				index.update(graph, &[], &[edge_index], &[face_index, new_face_index]);
+				edge_index
+			},
+			None => {
+				let edge_index = add_edge(graph, p, q, assignment);
+				index.update(graph, &[], &[edge_index], &[]);
+				edge_index
+			},
+		};
+		if graph.edges_fold_angle.len() > edge_index {
+			graph.edges_fold_angle[edge_index] = fold_angle.clone();
+		}
+		edges.push(edge_index);
+	}
+
+	return edges;
+}
+
+pub fn insert_segment(graph: &mut Graph, a: &Point, b: &Point, assignment: EdgeAssignment) -> Vec<usize> {
+	let mut index = SpatialIndex::new(graph);
+	let tolerance = merge_tolerance(bounding_size(graph, &[a, b]));
+	let edges = insert_segment_with_fold_angle(graph, &mut index, tolerance, a, b, assignment, assignment_fold_angle(assignment));
+	rebuild_topology(graph);
+	return edges;
+}
\ No newline at end of file
@@ -0,0 +1,117 @@
+use crate::fold::{Graph, EdgeAssignment};
+use crate::geometry::Point;
+use crate::graph::make;
+use crate::real::Real;
+
+pub fn assignment_fold_angle(assignment: EdgeAssignment) -> Real {
+	return match assignment {
+		EdgeAssignment::Mountain => Real::from_f64(-180.0),
+		EdgeAssignment::Valley => Real::from_f64(180.0),
+		_ => Real::from_f64(0.0),
+	};
+}
+
+pub fn vertex_point(graph: &Graph, vertex_index: usize) -> Point {
+	return Point::from_coordinates(&graph.vertices_coordinates[vertex_index]);
+}
+
+pub fn add_vertex(graph: &mut Graph, point: &Point) -> usize {
+	let mut coordinates = point.to_coordinates();
+	if let Some(vertex_coordinates) = graph.vertices_coordinates.first() {
+		coordinates.resize(vertex_coordinates.len().max(2), Real::from_f64(0.0));
+	}
+	graph.vertices_coordinates.push(coordinates);
+	return graph.vertices_coordinates.len() - 1;
+}
+
+pub fn add_edge(graph: &mut Graph, a: usize, b: usize, assignment: EdgeAssignment) -> usize {
+	let has_assignment = graph.edges_assignment.len() == graph.edges_vertices.len();
+	let has_fold_angle = graph.edges_fold_angle.len() == graph.edges_vertices.len();
+	let has_length = graph.edges_length.len() == graph.edges_vertices.len();
+
+	graph.edges_vertices.push(vec![a, b]);
+	if has_assignment { graph.edges_assignment.push(assignment); }
+	if has_fold_angle { graph.edges_fold_angle.push(assignment_fold_angle(assignment)); }
+	if has_length { graph.edges_length.push((&vertex_point(graph, b) - &vertex_point(graph, a)).length()); }
+	return graph.edges_vertices.len() - 1;
+}
+
+pub fn split_edge(graph: &mut Graph, edge_index: usize, vertex_index: usize) -> usize {
+	let (a, b) = (graph.edges_vertices[edge_index][0], graph.edges_vertices[edge_index][1]);
+	let new_edge_index = graph.edges_vertices.len();
+	graph.edges_vertices[edge_index] = vec![a, vertex_index];
+	graph.edges_vertices.push(vec![vertex_index, b]);
+
+	if graph.edges_assignment.len() == new_edge_index {
+		graph.edges_assignment.push(graph.edges_assignment[edge_index]);
+	}
+	if graph.edges_fold_angle.len() == new_edge_index {
+		graph.edges_fold_angle.push(graph.edges_fold_angle[edge_index].clone());
+	}
+	if graph.edges_length.len() == new_edge_index {
+		let point = vertex_point(graph, vertex_index);
+		graph.edges_length[edge_index] = (&point - &vertex_point(graph, a)).length();
+		graph.edges_length.push((&vertex_point(graph, b) - &point).length());
+	}
+
+	for face_vertices in graph.faces_vertices.iter_mut() {
+		let d = face_vertices.len();
+		for index in 0..d {
+			let (u, v) = (face_vertices[index], face_vertices[(index + 1) % d]);
+			if (u, v) == (a, b) || (u, v) == (b, a) {
+				face_vertices.insert(index + 1, vertex_index);
+				break;
+			}
+		}
+	}
+
+	let mut edge_orders = Vec::new();
+	for &(e1, e2, order) in graph.edge_orders.iter() {
+		if e1 == edge_index { edge_orders.push((new_edge_index, e2, order)); }
+		if e2 == edge_index { edge_orders.push((e1, new_edge_index, order)); }
+	}
+	graph.edge_orders.extend(edge_orders);
+
+	return new_edge_index;
+}
+
+pub fn split_face(graph: &mut Graph, face_index: usize, a: usize, b: usize, assignment: EdgeAssignment) -> Option<(usize, usize)> {
+	let face_vertices = graph.faces_vertices[face_index].clone();
+	let index_a = face_vertices.iter().position(|&v| v == a)?;
+	let index_b = face_vertices.iter().position(|&v| v == b)?;
+	let d = face_vertices.len();
+
+	let mut first: Vec<usize> = Vec::new();
+	let mut index = index_a;
+	while index != index_b { first.push(face_vertices[index]); index = (index + 1) % d; }
+	first.push(b);
+
+	let mut second: Vec<usize> = Vec::new();
+	while index != index_a { second.push(face_vertices[index]); index = (index + 1) % d; }
+	second.push(a);
+
+	if first.len() < 3 || second.len() < 3 { return None; }
+
+	let edge_index = add_edge(graph, a, b, assignment);
+	graph.faces_vertices[face_index] = first;
+	graph.faces_vertices.push(second);
+	let new_face_index = graph.faces_vertices.len() - 1;
+
+	let mut face_orders = Vec::new();
+	for &(f1, f2, order) in graph.face_orders.iter() {
+		if f1 == face_index { face_orders.push((new_face_index, f2, order)); }
+		if f2 == face_index { face_orders.push((f1, new_face_index, order)); }
+	}
+	graph.face_orders.extend(face_orders);
+
+	return Some((edge_index, new_face_index));
+}
+
+pub fn rebuild_topology(graph: &mut Graph) {
+	if graph.vertices_vertices.len() > 0 { graph.vertices_vertices = make::make_vertices_vertices(graph); }
+	if graph.vertices_edges.len() > 0 { graph.vertices_edges = make::make_vertices_edges(graph); }
+	if graph.vertices_faces.len() > 0 { graph.vertices_faces = make::make_vertices_faces(graph); }
+	if graph.edges_faces.len() > 0 { graph.edges_faces = make::make_edges_faces(graph); }
+	if graph.faces_edges.len() > 0 { graph.faces_edges = make::make_faces_edges(graph); }
+	if graph.faces_faces.len() > 0 { graph.faces_faces = make::make_faces_faces(graph); }
+}
\ No newline at end of file
@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+
+use crate::fold::{Graph, EdgeAssignment};
+use super::vertices::{vertex_position, distance_squared, match_vertices, match_moved_vertices};
+
+pub const DIFF_TOLERANCE: f64 = 1e-6;
+
+#[derive(Default, Clone, Debug)]
+pub struct GraphDiff {
+	pub vertices_map: Vec<Option<usize>>,
+	pub added_vertices: Vec<usize>,
+	pub removed_vertices: Vec<usize>,
+	pub moved_vertices: Vec<(usize, usize)>,
+
+	pub edges_map: Vec<Option<usize>>,
+	pub added_edges: Vec<usize>,
+	pub removed_edges: Vec<usize>,
+	pub flipped_edges: Vec<(usize, usize)>,
+	pub reassigned_edges: Vec<(usize, usize)>,
+	// Edges of a cut by new vertices, with their pieces in b, they are neither removed nor added
+	pub split_edges: Vec<(usize, Vec<usize>)>,
+}
+
+impl GraphDiff {
+	pub fn new() -> Self {
+		return Self { ..Default::default() }
+	}
+
+	pub fn is_empty(&self) -> bool {
+		return self.added_vertices.len() == 0 && self.removed_vertices.len() == 0 && self.moved_vertices.len() == 0
+			&& self.added_edges.len() == 0 && self.removed_edges.len() == 0
+			&& self.flipped_edges.len() == 0 && self.reassigned_edges.len() == 0 && self.split_edges.len() == 0;
+	}
+}
+
+fn edge_key(u: usize, v: usize) -> (usize, usize) {
+	return (usize::min(u, v), usize::max(u, v));
+}
+
+fn edge_assignment(graph: &Graph, edge_index: usize) -> EdgeAssignment {
+	return graph.edges_assignment.get(edge_index).copied().unwrap_or(EdgeAssignment::Unknown);
+}
+
+fn is_flip(a: EdgeAssignment, b: EdgeAssignment) -> bool {
+	return matches!((a, b), (EdgeAssignment::Mountain, EdgeAssignment::Valley) | (EdgeAssignment::Valley, EdgeAssignment::Mountain));
+}
+
+fn distance_to_segment(point: [f64; 3], a: [f64; 3], b: [f64; 3]) -> f64 {
+	let ab = [b[0] - a[0], b[1] - a[1], b[2] - a[2]];
+	let length_squared = ab[0] * ab[0] + ab[1] * ab[1] + ab[2] * ab[2];
+	if length_squared == 0.0 { return distance_squared(point, a).sqrt(); }
+	let t = ((point[0] - a[0]) * ab[0] + (point[1] - a[1]) * ab[1] + (point[2] - a[2]) * ab[2]) / length_squared;
+	let t = t.clamp(0.0, 1.0);
+	return distance_squared(point, [a[0] + t * ab[0], a[1] + t * ab[1], a[2] + t * ab[2]]).sqrt();
+}
+
+// An edge of a missing from b may only have been split by a crossing crease,
+// its pieces are then the unmatched edges of b lying on it, each may have been reassigned on its own
+fn split_pieces(a: &Graph, edge_index: usize, b: &Graph, is_matched_edge: &[bool], tolerance: f64) -> Option<Vec<usize>> {
+	let edge_vertices = &a.edges_vertices[edge_index];
+	let (start, end) = (vertex_position(a, edge_vertices[0]), vertex_position(a, edge_vertices[1]));
+	let pieces: Vec<usize> = (0..b.edges_vertices.len()).filter(|&piece_index| {
+		let piece_vertices = &b.edges_vertices[piece_index];
+		return !is_matched_edge[piece_index] && piece_vertices.iter().all(|&vertex_index| distance_to_segment(vertex_position(b, vertex_index), start, end) <= tolerance);
+	}).collect();
+	if pieces.len() < 2 { return None; }
+
+	let length: f64 = pieces.iter().map(|&piece_index| {
+		let piece_vertices = &b.edges_vertices[piece_index];
+		distance_squared(vertex_position(b, piece_vertices[0]), vertex_position(b, piece_vertices[1])).sqrt()
+	}).sum();
+	if (length - distance_squared(start, end).sqrt()).abs() > tolerance * pieces.len() as f64 { return None; }
+	return Some(pieces);
+}
+
+pub fn diff_with_tolerance(a: &Graph, b: &Graph, tolerance: f64) -> GraphDiff {
+	let mut diff = GraphDiff::new();
+	diff.vertices_map = match_vertices(a, b, tolerance);
+	diff.moved_vertices = match_moved_vertices(a, b, &mut diff.vertices_map);
+
+	let mut is_matched_b = vec![false; b.vertices_coordinates.len()];
+	for (a_index, vertex_map) in diff.vertices_map.iter().enumerate() {
+		match vertex_map {
+			Some(b_index) => is_matched_b[*b_index] = true,
+			None => diff.removed_vertices.push(a_index),
+		}
+	}
+	diff.added_vertices = (0..b.vertices_coordinates.len()).filter(|&b_index| !is_matched_b[b_index]).collect();
+
+	// Parallel edges between the same vertices are matched in order
+	let mut b_edges: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+	for (edge_index, edge_vertices) in b.edges_vertices.iter().enumerate().rev() {
+		b_edges.entry(edge_key(edge_vertices[0], edge_vertices[1])).or_default().push(edge_index);
+	}
+
+	let mut is_matched_edge = vec![false; b.edges_vertices.len()];
+	for (edge_index, edge_vertices) in a.edges_vertices.iter().enumerate() {
+		let endpoints = (diff.vertices_map.get(edge_vertices[0]).copied().flatten(), diff.vertices_map.get(edge_vertices[1]).copied().flatten());
+		let matched_edge = match endpoints {
+			(Some(u), Some(v)) => b_edges.get_mut(&edge_key(u, v)).and_then(|edges| edges.pop()),
+			_ => None,
+		};
+		diff.edges_map.push(matched_edge);
+
+		let Some(b_index) = matched_edge else { continue; };
+		is_matched_edge[b_index] = true;
+		let (a_assignment, b_assignment) = (edge_assignment(a, edge_index), edge_assignment(b, b_index));
+		if is_flip(a_assignment, b_assignment) {
+			diff.flipped_edges.push((edge_index, b_index));
+		} else if a_assignment != b_assignment {
+			diff.reassigned_edges.push((edge_index, b_index));
+		}
+	}
+
+	// Split edges are looked for once every edge matched directly has been taken
+	for edge_index in 0..a.edges_vertices.len() {
+		if diff.edges_map[edge_index].is_some() { continue; }
+		let Some(pieces) = split_pieces(a, edge_index, b, &is_matched_edge, tolerance) else {
+			diff.removed_edges.push(edge_index);
+			continue;
+		};
+		let a_assignment = edge_assignment(a, edge_index);
+		for &b_index in &pieces {
+			is_matched_edge[b_index] = true;
+			let b_assignment = edge_assignment(b, b_index);
+			if is_flip(a_assignment, b_assignment) {
+				diff.flipped_edges.push((edge_index, b_index));
+			} else if a_assignment != b_assignment {
+				diff.reassigned_edges.push((edge_index, b_index));
+			}
+		}
+		diff.split_edges.push((edge_index, pieces));
+	}
+	diff.added_edges = (0..b.edges_vertices.len()).filter(|&b_index| !is_matched_edge[b_index]).collect();
+	return diff;
+}
+
+pub fn diff(a: &Graph, b: &Graph) -> GraphDiff {
+	return diff_with_tolerance(a, b, DIFF_TOLERANCE);
+}
\ No newline at end of file
@@ -0,0 +1,49 @@
+use serde_json::Value;
+
+use crate::fold::{Frame, Graph, EdgeAssignment};
+use super::compare::GraphDiff;
+
+pub const VERTICES_STATUS_KEY: &str = "vertices_diff:status";
+pub const EDGES_STATUS_KEY: &str = "edges_diff:status";
+
+// The frame holds the vertices and edges of b, followed by what was removed from a
+pub fn make_diff_frame(a: &Graph, b: &Graph, diff: &GraphDiff, parent: Option<usize>) -> Frame {
+	let mut frame = Frame::new();
+	frame.parent = parent;
+	let graph = &mut frame.graph;
+	graph.vertices_coordinates = b.vertices_coordinates.clone();
+	let mut vertices_status: Vec<&str> = vec!["unchanged"; b.vertices_coordinates.len()];
+	for &b_index in &diff.added_vertices { vertices_status[b_index] = "added"; }
+	for &(_, b_index) in &diff.moved_vertices { vertices_status[b_index] = "moved"; }
+
+	let mut removed_vertices_map: Vec<Option<usize>> = diff.vertices_map.clone();
+	for &a_index in &diff.removed_vertices {
+		removed_vertices_map[a_index] = Some(graph.vertices_coordinates.len());
+		graph.vertices_coordinates.push(a.vertices_coordinates[a_index].clone());
+		vertices_status.push("removed");
+	}
+
+	graph.edges_vertices = b.edges_vertices.clone();
+	graph.edges_assignment = (0..b.edges_vertices.len())
+		.map(|edge_index| b.edges_assignment.get(edge_index).copied().unwrap_or(EdgeAssignment::Unknown))
+		.collect();
+	let mut edges_status: Vec<&str> = vec!["unchanged"; b.edges_vertices.len()];
+	for &b_index in &diff.added_edges { edges_status[b_index] = "added"; }
+	for (_, pieces) in &diff.split_edges {
+		for &b_index in pieces { edges_status[b_index] = "split"; }
+	}
+	for &(_, b_index) in &diff.flipped_edges { edges_status[b_index] = "flipped"; }
+	for &(_, b_index) in &diff.reassigned_edges { edges_status[b_index] = "reassigned"; }
+
+	for &edge_index in &diff.removed_edges {
+		let edge_vertices = &a.edges_vertices[edge_index];
+		let (Some(u), Some(v)) = (removed_vertices_map[edge_vertices[0]], removed_vertices_map[edge_vertices[1]]) else { continue; };
+		graph.edges_vertices.push(vec![u, v]);
+		graph.edges_assignment.push(a.edges_assignment.get(edge_index).copied().unwrap_or(EdgeAssignment::Unknown));
+		edges_status.push("removed");
+	}
+
+	frame.custom_data.insert(String::from(VERTICES_STATUS_KEY), Value::from(vertices_status));
+	frame.custom_data.insert(String::from(EDGES_STATUS_KEY), Value::from(edges_status));
+	return frame;
+}
\ No newline at end of file
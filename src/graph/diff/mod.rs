@@ -0,0 +1,14 @@
+mod vertices;
+mod compare;
+mod frame;
+
+pub use vertices::match_vertices;
+
+pub use compare::GraphDiff;
+pub use compare::DIFF_TOLERANCE;
+pub use compare::diff;
+pub use compare::diff_with_tolerance;
+
+pub use frame::VERTICES_STATUS_KEY;
+pub use frame::EDGES_STATUS_KEY;
+pub use frame::make_diff_frame;
\ No newline at end of file
@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+
+use crate::fold::Graph;
+
+pub fn vertex_position(graph: &Graph, vertex_index: usize) -> [f64; 3] {
+	let coordinates = &graph.vertices_coordinates[vertex_index];
+	let coordinate = |index: usize| coordinates.get(index).map(|value| value.to_f64()).unwrap_or(0.0);
+	return [coordinate(0), coordinate(1), coordinate(2)];
+}
+
+pub fn distance_squared(a: [f64; 3], b: [f64; 3]) -> f64 {
+	return (a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2);
+}
+
+// Vertices of b are hashed in a grid of the tolerance size, each vertex of a takes the nearest free one
+pub fn match_vertices(a: &Graph, b: &Graph, tolerance: f64) -> Vec<Option<usize>> {
+	let cell_size = if tolerance > 0.0 { tolerance } else { 1e-12 };
+	let cell = |position: [f64; 3]| position.map(|value| (value / cell_size).floor() as i64);
+
+	let mut grid: HashMap<[i64; 3], Vec<usize>> = HashMap::new();
+	for vertex_index in 0..b.vertices_coordinates.len() {
+		grid.entry(cell(vertex_position(b, vertex_index))).or_default().push(vertex_index);
+	}
+
+	let mut is_used = vec![false; b.vertices_coordinates.len()];
+	let mut vertices_map: Vec<Option<usize>> = vec![None; a.vertices_coordinates.len()];
+	for (vertex_index, vertex_map) in vertices_map.iter_mut().enumerate() {
+		let position = vertex_position(a, vertex_index);
+		let [x, y, z] = cell(position);
+		let mut nearest: Option<(f64, usize)> = None;
+		for dx in -1..=1 {
+			for dy in -1..=1 {
+				for dz in -1..=1 {
+					let Some(candidates) = grid.get(&[x + dx, y + dy, z + dz]) else { continue; };
+					for &candidate in candidates {
+						if is_used[candidate] { continue; }
+						let distance = distance_squared(position, vertex_position(b, candidate));
+						if distance > tolerance * tolerance { continue; }
+						if nearest.is_none_or(|(nearest_distance, _)| distance < nearest_distance) { nearest = Some((distance, candidate)); }
+					}
+				}
+			}
+		}
+		if let Some((_, candidate)) = nearest {
+			is_used[candidate] = true;
+			*vertex_map = Some(candidate);
+		}
+	}
+	return vertices_map;
+}
+
+fn vertices_neighbors(graph: &Graph) -> Vec<Vec<usize>> {
+	let mut vertices_neighbors: Vec<Vec<usize>> = vec![Vec::new(); graph.vertices_coordinates.len()];
+	for edge_vertices in &graph.edges_vertices {
+		let (u, v) = (edge_vertices[0], edge_vertices[1]);
+		if u >= vertices_neighbors.len() || v >= vertices_neighbors.len() { continue; }
+		vertices_neighbors[u].push(v);
+		vertices_neighbors[v].push(u);
+	}
+	return vertices_neighbors;
+}
+
+// A vertex left unmatched on both sides with the same matched neighbors has moved,
+// it is added to the map and returned as (a, b) pairs
+pub fn match_moved_vertices(a: &Graph, b: &Graph, vertices_map: &mut [Option<usize>]) -> Vec<(usize, usize)> {
+	let mut is_matched_b = vec![false; b.vertices_coordinates.len()];
+	for &b_index in vertices_map.iter().flatten() { is_matched_b[b_index] = true; }
+
+	let a_neighbors = vertices_neighbors(a);
+	let b_neighbors = vertices_neighbors(b);
+	let mut b_by_neighbors: HashMap<Vec<usize>, Vec<usize>> = HashMap::new();
+	for (b_index, neighbors) in b_neighbors.iter().enumerate() {
+		if is_matched_b[b_index] { continue; }
+		let mut key: Vec<usize> = neighbors.iter().copied().filter(|&neighbor| is_matched_b[neighbor]).collect();
+		if key.len() == 0 { continue; }
+		key.sort();
+		b_by_neighbors.entry(key).or_default().push(b_index);
+	}
+
+	let mut moved_vertices: Vec<(usize, usize)> = Vec::new();
+	for a_index in 0..vertices_map.len() {
+		if vertices_map[a_index].is_some() { continue; }
+		let mut key: Vec<usize> = a_neighbors[a_index].iter().filter_map(|&neighbor| vertices_map[neighbor]).collect();
+		if key.len() == 0 { continue; }
+		key.sort();
+		let Some(candidates) = b_by_neighbors.get_mut(&key) else { continue; };
+		if candidates.len() != 1 { continue; }
+		let b_index = candidates.pop().unwrap();
+		vertices_map[a_index] = Some(b_index);
+		moved_vertices.push((a_index, b_index));
+	}
+	return moved_vertices;
+}
\ No newline at end of file
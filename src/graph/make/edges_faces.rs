@@ -0,0 +1,17 @@
+use crate::fold::Graph;
+use super::pairs::make_cyclical_pairs_map;
+
+pub fn make_edges_faces(graph: &Graph) -> Vec<Vec<Option<usize>>> {
+	let pairs_map = make_cyclical_pairs_map(&graph.faces_vertices);
+	let mut edges_faces: Vec<Vec<Option<usize>>> = Vec::new();
+	for edge_vertices in graph.edges_vertices.iter() {
+		let (a, b) = (edge_vertices[0], edge_vertices[1]);
+		let left_face = pairs_map.get(&(a, b)).copied();
+		let right_face = pairs_map.get(&(b, a)).copied();
+		edges_faces.push(match (left_face, right_face) {
+			(None, None) => Vec::new(),
+			_ => vec![left_face, right_face],
+		});
+	}
+	return edges_faces;
+}
\ No newline at end of file
@@ -0,0 +1,22 @@
+use std::collections::HashMap;
+use crate::fold::Graph;
+
+pub fn make_faces_edges(graph: &Graph) -> Vec<Vec<usize>> {
+	let mut edges_map: HashMap<(usize, usize), usize> = HashMap::new();
+	for (edge_index, edge_vertices) in graph.edges_vertices.iter().enumerate() {
+		edges_map.insert((edge_vertices[0], edge_vertices[1]), edge_index);
+		edges_map.insert((edge_vertices[1], edge_vertices[0]), edge_index);
+	}
+
+	let mut faces_edges: Vec<Vec<usize>> = Vec::new();
+	for face_vertices in graph.faces_vertices.iter() {
+		let d = face_vertices.len();
+		let mut face_edges: Vec<usize> = Vec::new();
+		for (index, &face_vertex_index) in face_vertices.iter().enumerate() {
+			let pair = (face_vertex_index, face_vertices[(index + 1) % d]);
+			if let Some(&edge_index) = edges_map.get(&pair) { face_edges.push(edge_index); }
+		}
+		faces_edges.push(face_edges);
+	}
+	return faces_edges;
+}
\ No newline at end of file
@@ -0,0 +1,17 @@
+use crate::fold::Graph;
+use super::pairs::make_cyclical_pairs_map;
+
+pub fn make_faces_faces(graph: &Graph) -> Vec<Vec<Option<usize>>> {
+	let pairs_map = make_cyclical_pairs_map(&graph.faces_vertices);
+	let mut faces_faces: Vec<Vec<Option<usize>>> = Vec::new();
+	for face_vertices in graph.faces_vertices.iter() {
+		let d = face_vertices.len();
+		let mut face_faces: Vec<Option<usize>> = Vec::new();
+		for (index, &face_vertex_index) in face_vertices.iter().enumerate() {
+			let pair = (face_vertices[(index + 1) % d], face_vertex_index);
+			face_faces.push(pairs_map.get(&pair).copied());
+		}
+		faces_faces.push(face_faces);
+	}
+	return faces_faces;
+}
\ No newline at end of file
@@ -0,0 +1,37 @@
+use crate::fold::Graph;
+
+pub fn face_coordinates(graph: &Graph, face_vertices: &[usize]) -> Vec<[f64; 3]> {
+	let mut coordinates: Vec<[f64; 3]> = Vec::new();
+	for &vertex_index in face_vertices.iter() {
+		let mut point = [0.0; 3];
+		for (axis, value) in graph.vertices_coordinates[vertex_index].iter().take(3).enumerate() {
+			point[axis] = value.to_f64();
+		}
+		coordinates.push(point);
+	}
+	return coordinates;
+}
+
+pub fn face_normal(coordinates: &[[f64; 3]]) -> [f64; 3] {
+	let mut normal = [0.0; 3];
+	let d = coordinates.len();
+	for index in 0..d {
+		let a = coordinates[index];
+		let b = coordinates[(index + 1) % d];
+		normal[0] += (a[1] - b[1]) * (a[2] + b[2]);
+		normal[1] += (a[2] - b[2]) * (a[0] + b[0]);
+		normal[2] += (a[0] - b[0]) * (a[1] + b[1]);
+	}
+	return normal;
+}
+
+pub fn make_face_normal(graph: &Graph, face_vertices: &[usize]) -> [f64; 3] {
+	let normal = face_normal(&face_coordinates(graph, face_vertices));
+	let length = (normal[0] * normal[0] + normal[1] * normal[1] + normal[2] * normal[2]).sqrt();
+	if length == 0.0 { return [0.0, 0.0, 0.0]; }
+	return [normal[0] / length, normal[1] / length, normal[2] / length];
+}
+
+pub fn make_faces_normals(graph: &Graph) -> Vec<[f64; 3]> {
+	return graph.faces_vertices.iter().map(|face_vertices| make_face_normal(graph, face_vertices)).collect();
+}
\ No newline at end of file
@@ -0,0 +1,37 @@
+use std::collections::HashSet;
+
+use crate::fold::Graph;
+use crate::geometry::{Point, polygon_area};
+use super::vertices_vertices::make_vertices_vertices;
+
+pub fn make_faces_vertices(graph: &Graph) -> Vec<Vec<usize>> {
+	let vertices_vertices = make_vertices_vertices(graph);
+	let mut visited: HashSet<(usize, usize)> = HashSet::new();
+	let mut faces_vertices: Vec<Vec<usize>> = Vec::new();
+
+	for edge_vertices in graph.edges_vertices.iter() {
+		for &(start, next) in [(edge_vertices[0], edge_vertices[1]), (edge_vertices[1], edge_vertices[0])].iter() {
+			if visited.contains(&(start, next)) { continue; }
+
+			let mut face_vertices: Vec<usize> = Vec::new();
+			let (mut u, mut v) = (start, next);
+			while visited.insert((u, v)) {
+				face_vertices.push(u);
+				let vertex_vertices = &vertices_vertices[v];
+				let d = vertex_vertices.len();
+				let index = vertex_vertices.iter().position(|&w| w == u).unwrap();
+				let w = vertex_vertices[(index + d - 1) % d];
+				(u, v) = (v, w);
+			}
+
+			// The unbounded face is walked clockwise and has a negative area
+			let polygon: Vec<Point> = face_vertices.iter()
+				.map(|&v| Point::from_coordinates(&graph.vertices_coordinates[v]))
+				.collect();
+			if face_vertices.len() >= 3 && polygon_area(&polygon) > 0.0 {
+				faces_vertices.push(face_vertices);
+			}
+		}
+	}
+	return faces_vertices;
+}
\ No newline at end of file
@@ -1,17 +1,36 @@
 mod pairs;
+mod triangles;
 
-//mod vertices;
-//mod vertices_vertices;
-//mod vertices_edges;
-//mod vertices_faces;
+mod vertices_vertices;
+mod vertices_edges;
+mod vertices_faces;
 
 //mod edges;
 //mod edges_vertices;
 //mod edges_edges;
-//mod edges_faces;
+mod edges_faces;
 //mod edges_fold_angle;
 
 //mod faces;
-//mod faces_vertices;
-//mod faces_edges;
-//mod faces_faces;
\ No newline at end of file
+mod faces_vertices;
+mod faces_edges;
+mod faces_faces;
+mod faces_normals;
+
+pub use pairs::make_cyclical_pairs_map;
+pub use triangles::make_face_triangles;
+pub use triangles::make_faces_triangles;
+
+pub use vertices_vertices::make_vertices_vertices;
+pub use vertices_edges::make_vertices_edges;
+pub use vertices_edges::make_vertices_edges_unsorted;
+pub use vertices_faces::make_vertices_faces;
+
+pub use edges_faces::make_edges_faces;
+
+pub use faces_vertices::make_faces_vertices;
+pub use faces_edges::make_faces_edges;
+pub use faces_faces::make_faces_faces;
+
+pub use faces_normals::make_face_normal;
+pub use faces_normals::make_faces_normals;
\ No newline at end of file
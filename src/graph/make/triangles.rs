@@ -0,0 +1,69 @@
+use crate::fold::Graph;
+use super::faces_normals::{face_coordinates, face_normal};
+
+fn project_face(coordinates: &[[f64; 3]]) -> Vec<[f64; 2]> {
+	let normal = face_normal(coordinates);
+	let (x, y) = if normal[2].abs() >= normal[0].abs() && normal[2].abs() >= normal[1].abs() {
+		if normal[2] >= 0.0 { (0, 1) } else { (1, 0) }
+	} else if normal[1].abs() >= normal[0].abs() {
+		if normal[1] >= 0.0 { (2, 0) } else { (0, 2) }
+	} else {
+		if normal[0] >= 0.0 { (1, 2) } else { (2, 1) }
+	};
+	return coordinates.iter().map(|point| [point[x], point[y]]).collect();
+}
+
+fn cross(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> f64 {
+	return (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0]);
+}
+
+fn contains(a: [f64; 2], b: [f64; 2], c: [f64; 2], point: [f64; 2]) -> bool {
+	return cross(a, b, point) >= 0.0 && cross(b, c, point) >= 0.0 && cross(c, a, point) >= 0.0;
+}
+
+pub fn make_face_triangles(graph: &Graph, face_vertices: &[usize]) -> Vec<[usize; 3]> {
+	let mut triangles: Vec<[usize; 3]> = Vec::new();
+	if face_vertices.len() < 3 { return triangles; }
+
+	let points = project_face(&face_coordinates(graph, face_vertices));
+	let mut polygon: Vec<usize> = (0..face_vertices.len()).collect();
+
+	while polygon.len() > 3 {
+		let d = polygon.len();
+		let mut ear_option: Option<usize> = None;
+		for index in 0..d {
+			let previous = polygon[(index + d - 1) % d];
+			let current = polygon[index];
+			let next = polygon[(index + 1) % d];
+			let (a, b, c) = (points[previous], points[current], points[next]);
+			if cross(a, b, c) <= 0.0 { continue; }
+
+			let is_ear = polygon.iter()
+				.filter(|&&other| other != previous && other != current && other != next)
+				.all(|&other| !contains(a, b, c, points[other]));
+			if is_ear {
+				ear_option = Some(index);
+				break;
+			}
+		}
+
+		// Degenerate faces fall back to a fan around the first remaining vertex
+		let index = ear_option.unwrap_or(1);
+		let previous = polygon[(index + d - 1) % d];
+		let current = polygon[index];
+		let next = polygon[(index + 1) % d];
+		triangles.push([face_vertices[previous], face_vertices[current], face_vertices[next]]);
+		polygon.remove(index);
+	}
+
+	triangles.push([face_vertices[polygon[0]], face_vertices[polygon[1]], face_vertices[polygon[2]]]);
+	return triangles;
+}
+
+pub fn make_faces_triangles(graph: &Graph) -> Vec<Vec<[usize; 3]>> {
+	let mut faces_triangles: Vec<Vec<[usize; 3]>> = Vec::new();
+	for face_vertices in graph.faces_vertices.iter() {
+		faces_triangles.push(make_face_triangles(graph, face_vertices));
+	}
+	return faces_triangles;
+}
\ No newline at end of file
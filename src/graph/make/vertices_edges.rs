@@ -0,0 +1,38 @@
+use crate::fold::Graph;
+
+fn angle(graph: &Graph, from: usize, to: usize) -> f64 {
+	let a = &graph.vertices_coordinates[from];
+	let b = &graph.vertices_coordinates[to];
+	let dx = b[0].to_f64() - a[0].to_f64();
+	let dy = b[1].to_f64() - a[1].to_f64();
+	return dy.atan2(dx);
+}
+
+pub fn make_vertices_edges_unsorted(graph: &Graph) -> Vec<Vec<usize>> {
+	let mut vertices_edges: Vec<Vec<usize>> = vec![Vec::new(); graph.vertices_coordinates.len()];
+	for (edge_index, edge_vertices) in graph.edges_vertices.iter().enumerate() {
+		for &vertex_index in edge_vertices.iter() {
+			if vertex_index >= vertices_edges.len() { vertices_edges.resize(vertex_index + 1, Vec::new()); }
+			vertices_edges[vertex_index].push(edge_index);
+		}
+	}
+	return vertices_edges;
+}
+
+pub fn make_vertices_edges(graph: &Graph) -> Vec<Vec<usize>> {
+	let mut vertices_edges = make_vertices_edges_unsorted(graph);
+	let is_planar = graph.vertices_coordinates.iter().all(|coordinates| coordinates.len() >= 2);
+	if !is_planar { return vertices_edges; }
+
+	for (vertex_index, vertex_edges) in vertices_edges.iter_mut().enumerate() {
+		let mut edges_angle: Vec<(f64, usize)> = Vec::new();
+		for &edge_index in vertex_edges.iter() {
+			let edge_vertices = &graph.edges_vertices[edge_index];
+			let other_index = if edge_vertices[0] == vertex_index { edge_vertices[1] } else { edge_vertices[0] };
+			edges_angle.push((angle(graph, vertex_index, other_index), edge_index));
+		}
+		edges_angle.sort_by(|a, b| a.0.total_cmp(&b.0));
+		*vertex_edges = edges_angle.into_iter().map(|(_, edge_index)| edge_index).collect();
+	}
+	return vertices_edges;
+}
\ No newline at end of file
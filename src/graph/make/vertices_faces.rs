@@ -0,0 +1,16 @@
+use crate::fold::Graph;
+use super::pairs::make_cyclical_pairs_map;
+use super::vertices_vertices::make_vertices_vertices;
+
+pub fn make_vertices_faces(graph: &Graph) -> Vec<Vec<Option<usize>>> {
+	let pairs_map = make_cyclical_pairs_map(&graph.faces_vertices);
+	let mut vertices_faces: Vec<Vec<Option<usize>>> = Vec::new();
+	for (vertex_index, vertex_vertices) in make_vertices_vertices(graph).iter().enumerate() {
+		let mut vertex_faces: Vec<Option<usize>> = Vec::new();
+		for &vertex_vertex_index in vertex_vertices.iter() {
+			vertex_faces.push(pairs_map.get(&(vertex_index, vertex_vertex_index)).copied());
+		}
+		vertices_faces.push(vertex_faces);
+	}
+	return vertices_faces;
+}
\ No newline at end of file
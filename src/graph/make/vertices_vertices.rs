@@ -0,0 +1,16 @@
+use crate::fold::Graph;
+use super::vertices_edges::make_vertices_edges;
+
+pub fn make_vertices_vertices(graph: &Graph) -> Vec<Vec<usize>> {
+	let mut vertices_vertices: Vec<Vec<usize>> = Vec::new();
+	for (vertex_index, vertex_edges) in make_vertices_edges(graph).iter().enumerate() {
+		let mut vertex_vertices: Vec<usize> = Vec::new();
+		for &edge_index in vertex_edges.iter() {
+			let edge_vertices = &graph.edges_vertices[edge_index];
+			let other_index = if edge_vertices[0] == vertex_index { edge_vertices[1] } else { edge_vertices[0] };
+			vertex_vertices.push(other_index);
+		}
+		vertices_vertices.push(vertex_vertices);
+	}
+	return vertices_vertices;
+}
\ No newline at end of file
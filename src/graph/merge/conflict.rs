@@ -0,0 +1,14 @@
+use crate::fold::EdgeAssignment;
+
+// Edge and vertex indices refer to the base graph, except for added edges which refer to ours and theirs
+#[derive(Clone, Copy, Debug)]
+pub enum MergeConflict {
+	Assignment(usize, EdgeAssignment, EdgeAssignment),
+	DeletedInOurs(usize, EdgeAssignment),
+	DeletedInTheirs(usize, EdgeAssignment),
+	AddedAssignment(usize, usize),
+	MovedVertex(usize),
+	FoldAngle(usize),
+	// A branch split the edge into pieces that disagree while the other branch changed it
+	SplitPieces(usize),
+}
\ No newline at end of file
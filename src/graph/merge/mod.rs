@@ -0,0 +1,8 @@
+mod conflict;
+mod three_way;
+
+pub use conflict::MergeConflict;
+
+pub use three_way::GraphMerge;
+pub use three_way::merge;
+pub use three_way::merge_with_tolerance;
\ No newline at end of file
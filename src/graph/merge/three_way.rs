@@ -0,0 +1,173 @@
+use crate::fold::{Graph, EdgeAssignment};
+use crate::graph::arrangement::{planarize, assignment_fold_angle};
+use crate::graph::diff::{GraphDiff, DIFF_TOLERANCE, diff_with_tolerance};
+use crate::real::Real;
+use super::conflict::MergeConflict;
+
+// Conflicts are resolved in favor of ours, the merged graph is planarized again
+#[derive(Default, Clone, Debug)]
+pub struct GraphMerge {
+	pub graph: Graph,
+	pub conflicts: Vec<MergeConflict>,
+}
+
+type Segment = (Vec<Real>, Vec<Real>, EdgeAssignment, Real);
+
+#[derive(Clone, PartialEq)]
+enum EdgeState {
+	Kept(EdgeAssignment, Real),
+	// Pieces of a split edge that do not all share one assignment and fold angle
+	Split(Vec<Segment>),
+	Removed,
+}
+
+fn position(graph: &Graph, vertex_index: usize) -> [f64; 3] {
+	let coordinates = &graph.vertices_coordinates[vertex_index];
+	let coordinate = |index: usize| coordinates.get(index).map(|value| value.to_f64()).unwrap_or(0.0);
+	return [coordinate(0), coordinate(1), coordinate(2)];
+}
+
+fn distance(a: [f64; 3], b: [f64; 3]) -> f64 {
+	return ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2)).sqrt();
+}
+
+fn assignment(graph: &Graph, edge_index: usize) -> EdgeAssignment {
+	return graph.edges_assignment.get(edge_index).copied().unwrap_or(EdgeAssignment::Unknown);
+}
+
+fn fold_angle(graph: &Graph, edge_index: usize) -> Real {
+	return graph.edges_fold_angle.get(edge_index).cloned().unwrap_or_else(|| assignment_fold_angle(assignment(graph, edge_index)));
+}
+
+fn kept_state(graph: &Graph, edge_index: usize) -> EdgeState {
+	return EdgeState::Kept(assignment(graph, edge_index), fold_angle(graph, edge_index));
+}
+
+// A base edge split by a crossing crease in a branch keeps the state of its pieces when they agree
+fn edge_state(edge_index: usize, branch: &Graph, branch_diff: &GraphDiff) -> EdgeState {
+	if let Some(branch_index) = branch_diff.edges_map[edge_index] { return kept_state(branch, branch_index); }
+	let Some((_, pieces)) = branch_diff.split_edges.iter().find(|(base_index, _)| *base_index == edge_index) else {
+		return EdgeState::Removed;
+	};
+	let state = kept_state(branch, pieces[0]);
+	if pieces.iter().all(|&piece_index| kept_state(branch, piece_index) == state) { return state; }
+	return EdgeState::Split(pieces.iter().map(|&piece_index| branch_segment(branch, piece_index)).collect());
+}
+
+fn moved_position(diff: &GraphDiff, vertex_index: usize) -> Option<usize> {
+	return diff.moved_vertices.iter().find(|(base_index, _)| *base_index == vertex_index).map(|&(_, branch_index)| branch_index);
+}
+
+fn merge_vertices(base: &Graph, ours: &Graph, theirs: &Graph, ours_diff: &GraphDiff, theirs_diff: &GraphDiff, tolerance: f64, conflicts: &mut Vec<MergeConflict>) -> Vec<Vec<Real>> {
+	return (0..base.vertices_coordinates.len()).map(|vertex_index| {
+		let ours_moved = moved_position(ours_diff, vertex_index);
+		let theirs_moved = moved_position(theirs_diff, vertex_index);
+		if let (Some(ours_index), Some(theirs_index)) = (ours_moved, theirs_moved)
+			&& distance(position(ours, ours_index), position(theirs, theirs_index)) > tolerance {
+			conflicts.push(MergeConflict::MovedVertex(vertex_index));
+		}
+		if let Some(ours_index) = ours_moved { return ours.vertices_coordinates[ours_index].clone(); }
+		if let Some(theirs_index) = theirs_moved { return theirs.vertices_coordinates[theirs_index].clone(); }
+		return base.vertices_coordinates[vertex_index].clone();
+	}).collect();
+}
+
+// A branch changed an edge when its assignment or its fold angle differs from the base
+fn merge_state(edge_index: usize, base: EdgeState, ours: EdgeState, theirs: EdgeState, conflicts: &mut Vec<MergeConflict>) -> EdgeState {
+	return match (&ours, &theirs) {
+		(EdgeState::Kept(ours_assignment, _), EdgeState::Kept(theirs_assignment, _)) => {
+			if ours == theirs || theirs == base { return ours; }
+			if ours == base { return theirs; }
+			if ours_assignment == theirs_assignment {
+				conflicts.push(MergeConflict::FoldAngle(edge_index));
+			} else {
+				conflicts.push(MergeConflict::Assignment(edge_index, *ours_assignment, *theirs_assignment));
+			}
+			ours
+		},
+		(EdgeState::Removed, EdgeState::Kept(theirs_assignment, _)) => {
+			if theirs != base { conflicts.push(MergeConflict::DeletedInOurs(edge_index, *theirs_assignment)); }
+			EdgeState::Removed
+		},
+		(EdgeState::Kept(ours_assignment, _), EdgeState::Removed) => {
+			if ours == base { return EdgeState::Removed; }
+			conflicts.push(MergeConflict::DeletedInTheirs(edge_index, *ours_assignment));
+			ours
+		},
+		(EdgeState::Removed, EdgeState::Removed) => EdgeState::Removed,
+		(EdgeState::Split(_), _) | (_, EdgeState::Split(_)) => {
+			if ours == theirs || theirs == base { return ours; }
+			if ours == base { return theirs; }
+			conflicts.push(MergeConflict::SplitPieces(edge_index));
+			ours
+		},
+	};
+}
+
+fn same_segment(a: &Graph, a_index: usize, b: &Graph, b_index: usize, tolerance: f64) -> bool {
+	let (a_vertices, b_vertices) = (&a.edges_vertices[a_index], &b.edges_vertices[b_index]);
+	let (a0, a1) = (position(a, a_vertices[0]), position(a, a_vertices[1]));
+	let (b0, b1) = (position(b, b_vertices[0]), position(b, b_vertices[1]));
+	return (distance(a0, b0) <= tolerance && distance(a1, b1) <= tolerance)
+		|| (distance(a0, b1) <= tolerance && distance(a1, b0) <= tolerance);
+}
+
+fn branch_segment(graph: &Graph, edge_index: usize) -> Segment {
+	let edge_vertices = &graph.edges_vertices[edge_index];
+	return (graph.vertices_coordinates[edge_vertices[0]].clone(), graph.vertices_coordinates[edge_vertices[1]].clone(), assignment(graph, edge_index), fold_angle(graph, edge_index));
+}
+
+fn make_merged_graph(segments: &[Segment]) -> Graph {
+	let mut graph = Graph::new();
+	for (a, b, segment_assignment, segment_fold_angle) in segments {
+		let vertex_index = graph.vertices_coordinates.len();
+		graph.vertices_coordinates.push(a.clone());
+		graph.vertices_coordinates.push(b.clone());
+		graph.edges_vertices.push(vec![vertex_index, vertex_index + 1]);
+		graph.edges_assignment.push(*segment_assignment);
+		graph.edges_fold_angle.push(segment_fold_angle.clone());
+	}
+	planarize(&mut graph);
+	return graph;
+}
+
+pub fn merge_with_tolerance(base: &Graph, ours: &Graph, theirs: &Graph, tolerance: f64) -> GraphMerge {
+	let ours_diff = diff_with_tolerance(base, ours, tolerance);
+	let theirs_diff = diff_with_tolerance(base, theirs, tolerance);
+	let mut merge = GraphMerge::default();
+	let vertices_coordinates = merge_vertices(base, ours, theirs, &ours_diff, &theirs_diff, tolerance, &mut merge.conflicts);
+
+	let mut segments: Vec<Segment> = Vec::new();
+	for (edge_index, edge_vertices) in base.edges_vertices.iter().enumerate() {
+		let ours_state = edge_state(edge_index, ours, &ours_diff);
+		let theirs_state = edge_state(edge_index, theirs, &theirs_diff);
+		let state = merge_state(edge_index, kept_state(base, edge_index), ours_state, theirs_state, &mut merge.conflicts);
+		match state {
+			EdgeState::Kept(edge_assignment, edge_fold_angle) => {
+				segments.push((vertices_coordinates[edge_vertices[0]].clone(), vertices_coordinates[edge_vertices[1]].clone(), edge_assignment, edge_fold_angle));
+			},
+			EdgeState::Split(pieces) => segments.extend(pieces),
+			EdgeState::Removed => {},
+		}
+	}
+
+	for &edge_index in &ours_diff.added_edges {
+		segments.push(branch_segment(ours, edge_index));
+	}
+	for &edge_index in &theirs_diff.added_edges {
+		let same_edge = ours_diff.added_edges.iter().copied().find(|&ours_index| same_segment(ours, ours_index, theirs, edge_index, tolerance));
+		match same_edge {
+			Some(ours_index) => if assignment(ours, ours_index) != assignment(theirs, edge_index) {
+				merge.conflicts.push(MergeConflict::AddedAssignment(ours_index, edge_index));
+			},
+			None => segments.push(branch_segment(theirs, edge_index)),
+		}
+	}
+
+	merge.graph = make_merged_graph(&segments);
+	return merge;
+}
+
+pub fn merge(base: &Graph, ours: &Graph, theirs: &Graph) -> GraphMerge {
+	return merge_with_tolerance(base, ours, theirs, DIFF_TOLERANCE);
+}
\ No newline at end of file
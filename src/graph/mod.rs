@@ -1,2 +1,12 @@
 pub mod validation;
-pub mod make;
\ No newline at end of file
+pub mod make;
+pub mod arrangement;
+pub mod diff;
+pub mod merge;
+pub mod spatial;
+pub mod snap;
+pub mod symmetry;
+pub mod transform;
+
+pub use diff::diff;
+pub use merge::merge;
@@ -0,0 +1,62 @@
+use crate::geometry::Point;
+use crate::real::Real;
+
+// Listed by priority, candidates of an earlier kind rank first whatever their distance
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SnapKind {
+	Vertex(usize),
+	Intersection(usize, usize),
+	RayIntersection(usize, usize),
+	EdgeMidpoint(usize),
+	GridPoint(i64, i64),
+	Ray(usize),
+}
+
+#[derive(Clone, Debug)]
+pub struct SnapCandidate {
+	pub kind: SnapKind,
+	pub point: Point,
+	pub distance: f64,
+}
+
+// Rays start from the anchor, usually the first point of the crease being drawn,
+// in angle_divisions directions around it, 16 giving multiples of 22.5°
+#[derive(Clone, Debug)]
+pub struct SnapOptions {
+	pub radius: f64,
+	pub grid_spacing: Option<Real>,
+	pub grid_origin: Point,
+	pub anchor: Option<Point>,
+	pub angle_divisions: usize,
+}
+
+impl Default for SnapOptions {
+	fn default() -> Self {
+		return Self {
+			radius: 0.01,
+			grid_spacing: None,
+			grid_origin: Point::from_f64(0.0, 0.0),
+			anchor: None,
+			angle_divisions: 16,
+		}
+	}
+}
+
+impl SnapOptions {
+	pub fn new(radius: f64) -> Self {
+		return Self { radius, ..Default::default() }
+	}
+}
+
+impl SnapKind {
+	pub fn priority(&self) -> usize {
+		return match self {
+			SnapKind::Vertex(_) => 0,
+			SnapKind::Intersection(_, _) => 1,
+			SnapKind::RayIntersection(_, _) => 2,
+			SnapKind::EdgeMidpoint(_) => 3,
+			SnapKind::GridPoint(_, _) => 4,
+			SnapKind::Ray(_) => 5,
+		};
+	}
+}
\ No newline at end of file
@@ -0,0 +1,9 @@
+mod candidate;
+mod search;
+
+pub use candidate::SnapKind;
+pub use candidate::SnapCandidate;
+pub use candidate::SnapOptions;
+
+pub use search::snap;
+pub use search::ray_direction;
\ No newline at end of file
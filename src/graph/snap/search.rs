@@ -0,0 +1,130 @@
+use crate::fold::Graph;
+use crate::geometry::{Point, Line};
+use crate::geometry::predicates::{SegmentIntersection, segment_intersection};
+use crate::graph::spatial::SpatialIndex;
+use crate::real::Real;
+use super::candidate::{SnapKind, SnapCandidate, SnapOptions};
+
+fn distance(a: &Point, b: &Point) -> f64 {
+	return (a - b).length().to_f64();
+}
+
+fn vertex_point(graph: &Graph, vertex_index: usize) -> Point {
+	return Point::from_coordinates(&graph.vertices_coordinates[vertex_index]);
+}
+
+fn edge_points(graph: &Graph, edge_index: usize) -> (Point, Point) {
+	let edge_vertices = &graph.edges_vertices[edge_index];
+	return (vertex_point(graph, edge_vertices[0]), vertex_point(graph, edge_vertices[1]));
+}
+
+// Directions on the axes and diagonals are built without trigonometry to stay exact
+pub fn ray_direction(direction_index: usize, angle_divisions: usize) -> Point {
+	let (one, zero) = (Real::from_f64(1.0), Real::from_f64(0.0));
+	if (8 * direction_index).is_multiple_of(angle_divisions) {
+		let octant = (8 * direction_index / angle_divisions) % 8;
+		let diagonal = Real::from_f64(0.5).sqrt();
+		return match octant {
+			0 => Point::new(one, zero),
+			1 => Point::new(diagonal.clone(), diagonal),
+			2 => Point::new(zero, one),
+			3 => Point::new(-&diagonal, diagonal),
+			4 => Point::new(-one, zero),
+			5 => Point::new(-&diagonal, -diagonal),
+			6 => Point::new(zero, -one),
+			_ => Point::new(diagonal.clone(), -diagonal),
+		};
+	}
+	let angle = Real::from_f64(2.0) * Real::pi() * Real::from_f64(direction_index as f64) / Real::from_f64(angle_divisions as f64);
+	let right_angle = Real::pi() / Real::from_f64(2.0);
+	return Point::new(angle.cos(), (&angle - &right_angle).cos());
+}
+
+fn vertex_candidates(graph: &Graph, index: &SpatialIndex, cursor: &Point, radius: f64, candidates: &mut Vec<SnapCandidate>) {
+	for vertex_index in index.vertices_within(cursor, radius) {
+		let point = vertex_point(graph, vertex_index);
+		candidates.push(SnapCandidate { kind: SnapKind::Vertex(vertex_index), distance: distance(cursor, &point), point });
+	}
+}
+
+fn edge_candidates(graph: &Graph, edges: &[usize], cursor: &Point, radius: f64, candidates: &mut Vec<SnapCandidate>) {
+	for (position, &edge_index) in edges.iter().enumerate() {
+		let (a, b) = edge_points(graph, edge_index);
+		let midpoint = a.midpoint(&b);
+		let midpoint_distance = distance(cursor, &midpoint);
+		if midpoint_distance <= radius {
+			candidates.push(SnapCandidate { kind: SnapKind::EdgeMidpoint(edge_index), point: midpoint, distance: midpoint_distance });
+		}
+
+		// Crossing edges only exist before the graph is planarized
+		for &other_index in &edges[position + 1..] {
+			let (c, d) = edge_points(graph, other_index);
+			if segment_intersection(&a, &b, &c, &d) != SegmentIntersection::Proper { continue; }
+			let Some(point) = Line::through(&a, &b).intersection(&Line::through(&c, &d)) else { continue; };
+			let point_distance = distance(cursor, &point);
+			if point_distance > radius { continue; }
+			candidates.push(SnapCandidate { kind: SnapKind::Intersection(edge_index, other_index), point, distance: point_distance });
+		}
+	}
+}
+
+fn grid_candidate(cursor: &Point, options: &SnapOptions, candidates: &mut Vec<SnapCandidate>) {
+	let Some(spacing) = &options.grid_spacing else { return; };
+	if *spacing <= 0.0 { return; }
+	let offset = cursor - &options.grid_origin;
+	let i = (&offset.x / spacing).to_f64().round();
+	let j = (&offset.y / spacing).to_f64().round();
+	let grid_offset = Point::new(spacing * &Real::from_f64(i), spacing * &Real::from_f64(j));
+	let point = &options.grid_origin + &grid_offset;
+	let point_distance = distance(cursor, &point);
+	if point_distance > options.radius { return; }
+	candidates.push(SnapCandidate { kind: SnapKind::GridPoint(i as i64, j as i64), point, distance: point_distance });
+}
+
+fn ray_candidates(graph: &Graph, edges: &[usize], cursor: &Point, options: &SnapOptions, candidates: &mut Vec<SnapCandidate>) {
+	let Some(anchor) = &options.anchor else { return; };
+	if options.angle_divisions == 0 { return; }
+	let mut nearest_ray: Option<SnapCandidate> = None;
+	for direction_index in 0..options.angle_divisions {
+		let ray = Line::new(anchor.clone(), ray_direction(direction_index, options.angle_divisions));
+		let parameter = ray.parameter(cursor);
+		if parameter < 0.0 { continue; }
+		let point = ray.point_at(&parameter);
+		let point_distance = distance(cursor, &point);
+		if point_distance > options.radius { continue; }
+
+		for &edge_index in edges {
+			let (a, b) = edge_points(graph, edge_index);
+			let edge = Line::through(&a, &b);
+			let (Some(ray_parameter), Some(edge_parameter)) = (ray.intersection_parameter(&edge), edge.intersection_parameter(&ray)) else { continue; };
+			if ray_parameter <= 0.0 || !(Real::from_f64(0.0)..=Real::from_f64(1.0)).contains(&edge_parameter) { continue; }
+			let intersection = ray.point_at(&ray_parameter);
+			let intersection_distance = distance(cursor, &intersection);
+			if intersection_distance > options.radius { continue; }
+			candidates.push(SnapCandidate { kind: SnapKind::RayIntersection(direction_index, edge_index), point: intersection, distance: intersection_distance });
+		}
+
+		if nearest_ray.as_ref().is_none_or(|nearest| point_distance < nearest.distance) {
+			nearest_ray = Some(SnapCandidate { kind: SnapKind::Ray(direction_index), point, distance: point_distance });
+		}
+	}
+	candidates.extend(nearest_ray);
+}
+
+// Candidates within the radius of the cursor, by priority then distance, the same point only once
+pub fn snap(graph: &Graph, index: &SpatialIndex, cursor: &Point, options: &SnapOptions) -> Vec<SnapCandidate> {
+	let mut candidates: Vec<SnapCandidate> = Vec::new();
+	let edges = index.edges_within(cursor, options.radius);
+	vertex_candidates(graph, index, cursor, options.radius, &mut candidates);
+	edge_candidates(graph, &edges, cursor, options.radius, &mut candidates);
+	ray_candidates(graph, &edges, cursor, options, &mut candidates);
+	grid_candidate(cursor, options, &mut candidates);
+
+	candidates.sort_by(|a, b| a.kind.priority().cmp(&b.kind.priority()).then(a.distance.total_cmp(&b.distance)));
+	let mut snapped: Vec<SnapCandidate> = Vec::new();
+	for candidate in candidates {
+		if snapped.iter().any(|other| other.point == candidate.point) { continue; }
+		snapped.push(candidate);
+	}
+	return snapped;
+}
\ No newline at end of file
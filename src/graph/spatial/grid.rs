@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+
+pub type Cell = [i64; 2];
+
+// Elements registered in every cell they overlap, along with the cells of each element to remove it again
+#[derive(Default, Clone, Debug)]
+pub struct Grid {
+	pub cells: HashMap<Cell, Vec<usize>>,
+	pub elements_cells: Vec<Vec<Cell>>,
+}
+
+impl Grid {
+	pub fn insert(&mut self, element_index: usize, cells: Vec<Cell>) {
+		self.remove(element_index);
+		if element_index >= self.elements_cells.len() { self.elements_cells.resize(element_index + 1, Vec::new()); }
+		for cell in cells.iter() {
+			self.cells.entry(*cell).or_default().push(element_index);
+		}
+		self.elements_cells[element_index] = cells;
+	}
+
+	pub fn remove(&mut self, element_index: usize) {
+		let Some(cells) = self.elements_cells.get_mut(element_index) else { return; };
+		for cell in std::mem::take(cells) {
+			let Some(elements) = self.cells.get_mut(&cell) else { continue; };
+			elements.retain(|&index| index != element_index);
+			if elements.len() == 0 { self.cells.remove(&cell); }
+		}
+	}
+
+	pub fn truncate(&mut self, elements_count: usize) {
+		for element_index in elements_count..self.elements_cells.len() {
+			self.remove(element_index);
+		}
+		self.elements_cells.truncate(elements_count);
+	}
+
+	// Elements overlapping the cells from min to max, each listed once,
+	// a rectangle too large to count its cells scans the occupied ones
+	pub fn elements_in(&self, min: Cell, max: Cell) -> Vec<usize> {
+		let mut elements: Vec<usize> = Vec::new();
+		let side = |axis: usize| max[axis].checked_sub(min[axis]).and_then(|length| length.checked_add(1));
+		let cells_count = side(0).zip(side(1)).and_then(|(width, height)| width.checked_mul(height));
+		if cells_count.is_none_or(|cells_count| cells_count > self.cells.len() as i64) {
+			for (cell, cell_elements) in self.cells.iter() {
+				if cell[0] < min[0] || cell[0] > max[0] || cell[1] < min[1] || cell[1] > max[1] { continue; }
+				elements.extend(cell_elements.iter().copied());
+			}
+		} else {
+			for x in min[0]..=max[0] {
+				for y in min[1]..=max[1] {
+					let Some(cell_elements) = self.cells.get(&[x, y]) else { continue; };
+					elements.extend(cell_elements.iter().copied());
+				}
+			}
+		}
+		elements.sort();
+		elements.dedup();
+		return elements;
+	}
+}
\ No newline at end of file
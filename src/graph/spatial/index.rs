@@ -0,0 +1,253 @@
+use crate::edit::GraphChange;
+use crate::fold::Graph;
+use crate::geometry::Point;
+use super::grid::{Cell, Grid};
+use super::query::{distance_squared, segment_distance_squared, segments_distance_squared, rect_contains, segment_intersects_rect, polygon_contains_f64};
+
+#[derive(Default, Clone, Debug)]
+pub struct RectQuery {
+	pub vertices: Vec<usize>,
+	pub edges: Vec<usize>,
+	pub faces: Vec<usize>,
+}
+
+// Uniform grid over the xy plane, elements keep a copy of their positions so queries do not need the graph
+#[derive(Default, Clone, Debug)]
+pub struct SpatialIndex {
+	pub cell_size: f64,
+	vertices: Grid,
+	edges: Grid,
+	faces: Grid,
+	vertices_positions: Vec<[f64; 2]>,
+	edges_segments: Vec<[[f64; 2]; 2]>,
+	faces_polygons: Vec<Vec<[f64; 2]>>,
+}
+
+fn vertex_position(graph: &Graph, vertex_index: usize) -> [f64; 2] {
+	let coordinates = &graph.vertices_coordinates[vertex_index];
+	let coordinate = |index: usize| coordinates.get(index).map(|value| value.to_f64()).unwrap_or(0.0);
+	return [coordinate(0), coordinate(1)];
+}
+
+fn point_position(point: &Point) -> [f64; 2] {
+	return [point.x.to_f64(), point.y.to_f64()];
+}
+
+// About one vertex per cell on evenly spread patterns
+fn default_cell_size(graph: &Graph) -> f64 {
+	let vertices_count = graph.vertices_coordinates.len();
+	if vertices_count == 0 { return 1.0; }
+	let (mut min, mut max) = ([f64::INFINITY; 2], [f64::NEG_INFINITY; 2]);
+	for vertex_index in 0..vertices_count {
+		let position = vertex_position(graph, vertex_index);
+		for axis in 0..2 {
+			min[axis] = min[axis].min(position[axis]);
+			max[axis] = max[axis].max(position[axis]);
+		}
+	}
+	let size = (max[0] - min[0]).max(max[1] - min[1]) / (vertices_count as f64).sqrt();
+	return if size > 0.0 && size.is_finite() { size } else { 1.0 };
+}
+
+impl SpatialIndex {
+	pub fn new(graph: &Graph) -> Self {
+		return Self::with_cell_size(graph, default_cell_size(graph));
+	}
+
+	pub fn with_cell_size(graph: &Graph, cell_size: f64) -> Self {
+		let mut index = Self { cell_size, ..Default::default() };
+		let vertices: Vec<usize> = (0..graph.vertices_coordinates.len()).collect();
+		let edges: Vec<usize> = (0..graph.edges_vertices.len()).collect();
+		let faces: Vec<usize> = (0..graph.faces_vertices.len()).collect();
+		index.update(graph, &vertices, &edges, &faces);
+		return index;
+	}
+
+	fn cell(&self, position: [f64; 2]) -> Cell {
+		return position.map(|value| (value / self.cell_size).floor() as i64);
+	}
+
+	fn cell_min(&self, cell: Cell) -> [f64; 2] {
+		return cell.map(|value| value as f64 * self.cell_size);
+	}
+
+	fn rect_cells(&self, min: [f64; 2], max: [f64; 2]) -> (Cell, Cell) {
+		return (self.cell(min), self.cell(max));
+	}
+
+	// Cells within the margin of the segment
+	fn segment_cells(&self, a: [f64; 2], b: [f64; 2], margin: f64) -> Vec<Cell> {
+		let (min, max) = self.rect_cells([a[0].min(b[0]) - margin, a[1].min(b[1]) - margin], [a[0].max(b[0]) + margin, a[1].max(b[1]) + margin]);
+		let mut cells: Vec<Cell> = Vec::new();
+		for x in min[0]..=max[0] {
+			for y in min[1]..=max[1] {
+				let cell_min = self.cell_min([x, y]);
+				let rect_min = [cell_min[0] - margin, cell_min[1] - margin];
+				let rect_max = [cell_min[0] + self.cell_size + margin, cell_min[1] + self.cell_size + margin];
+				if segment_intersects_rect(a, b, rect_min, rect_max) { cells.push([x, y]); }
+			}
+		}
+		return cells;
+	}
+
+	fn polygon_cells(&self, polygon: &[[f64; 2]]) -> Vec<Cell> {
+		if polygon.len() == 0 { return Vec::new(); }
+		let min = polygon.iter().fold([f64::INFINITY; 2], |min, position| [min[0].min(position[0]), min[1].min(position[1])]);
+		let max = polygon.iter().fold([f64::NEG_INFINITY; 2], |max, position| [max[0].max(position[0]), max[1].max(position[1])]);
+		let (min, max) = self.rect_cells(min, max);
+		return (min[0]..=max[0]).flat_map(|x| (min[1]..=max[1]).map(move |y| [x, y])).collect();
+	}
+
+	// Indices past the end of the graph are removed, changing a vertex does not update its edges and faces
+	pub fn update(&mut self, graph: &Graph, vertices: &[usize], edges: &[usize], faces: &[usize]) {
+		let vertices_count = graph.vertices_coordinates.len();
+		self.vertices.truncate(vertices_count);
+		self.vertices_positions.resize(vertices_count, [0.0; 2]);
+		for &vertex_index in vertices.iter().filter(|&&vertex_index| vertex_index < vertices_count) {
+			let position = vertex_position(graph, vertex_index);
+			self.vertices_positions[vertex_index] = position;
+			self.vertices.insert(vertex_index, vec![self.cell(position)]);
+		}
+
+		let edges_count = graph.edges_vertices.len();
+		self.edges.truncate(edges_count);
+		self.edges_segments.resize(edges_count, [[0.0; 2]; 2]);
+		for &edge_index in edges.iter().filter(|&&edge_index| edge_index < edges_count) {
+			let edge_vertices = &graph.edges_vertices[edge_index];
+			if edge_vertices.len() < 2 || edge_vertices.iter().any(|&vertex_index| vertex_index >= vertices_count) {
+				self.edges.remove(edge_index);
+				continue;
+			}
+			let segment = [vertex_position(graph, edge_vertices[0]), vertex_position(graph, edge_vertices[1])];
+			self.edges_segments[edge_index] = segment;
+			self.edges.insert(edge_index, self.segment_cells(segment[0], segment[1], self.cell_size * 1e-9));
+		}
+
+		let faces_count = graph.faces_vertices.len();
+		self.faces.truncate(faces_count);
+		self.faces_polygons.resize(faces_count, Vec::new());
+		for &face_index in faces.iter().filter(|&&face_index| face_index < faces_count) {
+			let face_vertices = &graph.faces_vertices[face_index];
+			if face_vertices.iter().any(|&vertex_index| vertex_index >= vertices_count) {
+				self.faces.remove(face_index);
+				continue;
+			}
+			let polygon: Vec<[f64; 2]> = face_vertices.iter().map(|&vertex_index| vertex_position(graph, vertex_index)).collect();
+			self.faces.insert(face_index, self.polygon_cells(&polygon));
+			self.faces_polygons[face_index] = polygon;
+		}
+	}
+
+	// Changes of an edit session already list the edges and faces incident to moved vertices
+	pub fn apply_change(&mut self, graph: &Graph, change: &GraphChange) {
+		self.update(graph, &change.vertices, &change.edges, &change.faces);
+	}
+
+	pub fn nearest_vertex(&self, point: &Point) -> Option<usize> {
+		let position = point_position(point);
+		let center = self.cell(position);
+		let occupied_count = self.vertices.cells.len() as i64;
+		let mut nearest: Option<(f64, usize)> = None;
+		let mut ring = 0;
+		loop {
+			// Past a certain ring size, scanning the occupied cells is cheaper than walking empty ones
+			let is_last = (2 * ring + 1) * (2 * ring + 1) >= occupied_count;
+			let candidates = if is_last {
+				self.vertices.cells.values().flatten().copied().collect()
+			} else {
+				self.vertices.elements_in(center.map(|value| value.saturating_sub(ring)), center.map(|value| value.saturating_add(ring)))
+			};
+			for vertex_index in candidates {
+				let distance = distance_squared(position, self.vertices_positions[vertex_index]);
+				if nearest.is_none_or(|(nearest_distance, _)| distance < nearest_distance) { nearest = Some((distance, vertex_index)); }
+			}
+			if is_last { break; }
+			if let Some((distance, _)) = nearest && (ring as f64 * self.cell_size).powi(2) >= distance { break; }
+			ring += 1;
+		}
+		return nearest.map(|(_, vertex_index)| vertex_index);
+	}
+
+	pub fn vertices_within(&self, point: &Point, radius: f64) -> Vec<usize> {
+		let position = point_position(point);
+		let (min, max) = self.rect_cells([position[0] - radius, position[1] - radius], [position[0] + radius, position[1] + radius]);
+		return self.vertices.elements_in(min, max).into_iter()
+			.filter(|&vertex_index| distance_squared(position, self.vertices_positions[vertex_index]) <= radius * radius)
+			.collect();
+	}
+
+	pub fn edges_within(&self, point: &Point, radius: f64) -> Vec<usize> {
+		let position = point_position(point);
+		let (min, max) = self.rect_cells([position[0] - radius, position[1] - radius], [position[0] + radius, position[1] + radius]);
+		return self.edges.elements_in(min, max).into_iter().filter(|&edge_index| {
+			let [a, b] = self.edges_segments[edge_index];
+			return segment_distance_squared(position, a, b) <= radius * radius;
+		}).collect();
+	}
+
+	fn elements_near_segment(&self, grid: &Grid, a: [f64; 2], b: [f64; 2], radius: f64) -> Vec<usize> {
+		let mut elements: Vec<usize> = self.segment_cells(a, b, radius).into_iter()
+			.flat_map(|cell| grid.elements_in(cell, cell))
+			.collect();
+		elements.sort();
+		elements.dedup();
+		return elements;
+	}
+
+	// Walks the cells along the segment rather than its bounding box, so long diagonals stay cheap
+	pub fn vertices_near_segment(&self, a: &Point, b: &Point, radius: f64) -> Vec<usize> {
+		let (a, b) = (point_position(a), point_position(b));
+		return self.elements_near_segment(&self.vertices, a, b, radius).into_iter()
+			.filter(|&vertex_index| segment_distance_squared(self.vertices_positions[vertex_index], a, b) <= radius * radius)
+			.collect();
+	}
+
+	pub fn edges_near_segment(&self, a: &Point, b: &Point, radius: f64) -> Vec<usize> {
+		let (a, b) = (point_position(a), point_position(b));
+		return self.elements_near_segment(&self.edges, a, b, radius).into_iter().filter(|&edge_index| {
+			let [c, d] = self.edges_segments[edge_index];
+			return segments_distance_squared(a, b, c, d) <= radius * radius;
+		}).collect();
+	}
+
+	// Faces containing the point or with a side within the radius of it
+	pub fn faces_near(&self, point: &Point, radius: f64) -> Vec<usize> {
+		let position = point_position(point);
+		let (min, max) = self.rect_cells([position[0] - radius, position[1] - radius], [position[0] + radius, position[1] + radius]);
+		return self.faces.elements_in(min, max).into_iter().filter(|&face_index| {
+			let polygon = &self.faces_polygons[face_index];
+			let d = polygon.len();
+			return polygon_contains_f64(polygon, position)
+				|| (0..d).any(|index| segment_distance_squared(position, polygon[index], polygon[(index + 1) % d]) <= radius * radius);
+		}).collect();
+	}
+
+	pub fn face_at(&self, point: &Point) -> Option<usize> {
+		let position = point_position(point);
+		let cell = self.cell(position);
+		return self.faces.elements_in(cell, cell).into_iter()
+			.find(|&face_index| polygon_contains_f64(&self.faces_polygons[face_index], position));
+	}
+
+	// Everything intersecting the rectangle between the two corners
+	pub fn in_rect(&self, a: &Point, b: &Point) -> RectQuery {
+		let (a, b) = (point_position(a), point_position(b));
+		let (min, max) = ([a[0].min(b[0]), a[1].min(b[1])], [a[0].max(b[0]), a[1].max(b[1])]);
+		let (min_cell, max_cell) = self.rect_cells(min, max);
+
+		let vertices = self.vertices.elements_in(min_cell, max_cell).into_iter()
+			.filter(|&vertex_index| rect_contains(min, max, self.vertices_positions[vertex_index]))
+			.collect();
+		let edges = self.edges.elements_in(min_cell, max_cell).into_iter().filter(|&edge_index| {
+			let [a, b] = self.edges_segments[edge_index];
+			return segment_intersects_rect(a, b, min, max);
+		}).collect();
+		let faces = self.faces.elements_in(min_cell, max_cell).into_iter().filter(|&face_index| {
+			let polygon = &self.faces_polygons[face_index];
+			let d = polygon.len();
+			return polygon_contains_f64(polygon, min)
+				|| (0..d).any(|index| segment_intersects_rect(polygon[index], polygon[(index + 1) % d], min, max));
+		}).collect();
+		return RectQuery { vertices, edges, faces };
+	}
+}
\ No newline at end of file
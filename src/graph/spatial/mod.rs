@@ -0,0 +1,6 @@
+mod grid;
+mod query;
+mod index;
+
+pub use index::SpatialIndex;
+pub use index::RectQuery;
\ No newline at end of file
@@ -0,0 +1,60 @@
+pub fn distance_squared(a: [f64; 2], b: [f64; 2]) -> f64 {
+	return (a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2);
+}
+
+pub fn segment_distance_squared(point: [f64; 2], a: [f64; 2], b: [f64; 2]) -> f64 {
+	let ab = [b[0] - a[0], b[1] - a[1]];
+	let length_squared = ab[0] * ab[0] + ab[1] * ab[1];
+	if length_squared == 0.0 { return distance_squared(point, a); }
+	let t = (((point[0] - a[0]) * ab[0] + (point[1] - a[1]) * ab[1]) / length_squared).clamp(0.0, 1.0);
+	return distance_squared(point, [a[0] + t * ab[0], a[1] + t * ab[1]]);
+}
+
+fn orientation(a: [f64; 2], b: [f64; 2], point: [f64; 2]) -> f64 {
+	return (b[0] - a[0]) * (point[1] - a[1]) - (b[1] - a[1]) * (point[0] - a[0]);
+}
+
+// Zero for crossing segments, otherwise the closest endpoint decides
+pub fn segments_distance_squared(a: [f64; 2], b: [f64; 2], c: [f64; 2], d: [f64; 2]) -> f64 {
+	let crosses = orientation(a, b, c) * orientation(a, b, d) < 0.0 && orientation(c, d, a) * orientation(c, d, b) < 0.0;
+	if crosses { return 0.0; }
+	return segment_distance_squared(a, c, d)
+		.min(segment_distance_squared(b, c, d))
+		.min(segment_distance_squared(c, a, b))
+		.min(segment_distance_squared(d, a, b));
+}
+
+pub fn rect_contains(min: [f64; 2], max: [f64; 2], point: [f64; 2]) -> bool {
+	return point[0] >= min[0] && point[0] <= max[0] && point[1] >= min[1] && point[1] <= max[1];
+}
+
+// Liang-Barsky clipping of the segment against the rectangle
+pub fn segment_intersects_rect(a: [f64; 2], b: [f64; 2], min: [f64; 2], max: [f64; 2]) -> bool {
+	let direction = [b[0] - a[0], b[1] - a[1]];
+	let (mut t0, mut t1) = (0.0, 1.0);
+	for axis in 0..2 {
+		for (p, q) in [(-direction[axis], a[axis] - min[axis]), (direction[axis], max[axis] - a[axis])] {
+			if p == 0.0 {
+				if q < 0.0 { return false; }
+				continue;
+			}
+			let t = q / p;
+			if p < 0.0 { t0 = f64::max(t0, t); } else { t1 = f64::min(t1, t); }
+			if t0 > t1 { return false; }
+		}
+	}
+	return true;
+}
+
+pub fn polygon_contains_f64(polygon: &[[f64; 2]], point: [f64; 2]) -> bool {
+	let d = polygon.len();
+	let mut inside = false;
+	for index in 0..d {
+		let (a, b) = (polygon[index], polygon[(index + 1) % d]);
+		if (a[1] > point[1]) != (b[1] > point[1]) {
+			let x = a[0] + (point[1] - a[1]) / (b[1] - a[1]) * (b[0] - a[0]);
+			if point[0] < x { inside = !inside; }
+		}
+	}
+	return inside;
+}
\ No newline at end of file
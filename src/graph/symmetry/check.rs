@@ -0,0 +1,50 @@
+use std::collections::HashSet;
+
+use crate::fold::{Graph, EdgeAssignment};
+use crate::geometry::Point;
+use crate::graph::spatial::SpatialIndex;
+use super::group::{Symmetry, symmetry_transforms, graph_bounds, is_in_bounds};
+use super::replicate::{image_edge, make_edges_map};
+
+// Transform indices refer to symmetry_transforms
+#[derive(Clone, Copy, Debug)]
+pub enum SymmetryViolation {
+	MissingImage(usize, usize),
+	AssignmentMismatch(usize, usize, EdgeAssignment, EdgeAssignment),
+}
+
+// The outline of the sheet rarely shares the symmetry of the pattern, so boundary edges
+// and images falling off the sheet do not count as missing
+pub fn check_symmetry(graph: &Graph, symmetries: &[Symmetry]) -> Vec<SymmetryViolation> {
+	if symmetries.len() == 0 { return Vec::new(); }
+	return check_symmetry_with_index(graph, symmetries, &SpatialIndex::new(graph));
+}
+
+pub fn check_symmetry_with_index(graph: &Graph, symmetries: &[Symmetry], index: &SpatialIndex) -> Vec<SymmetryViolation> {
+	let transforms = symmetry_transforms(graph, symmetries);
+	let Some(bounds) = graph_bounds(graph) else { return Vec::new(); };
+	if transforms.len() == 0 { return Vec::new(); }
+	let edges_map = make_edges_map(graph);
+	let assignment = |edge_index: usize| graph.edges_assignment.get(edge_index).copied().unwrap_or(EdgeAssignment::Unknown);
+	let is_image_on_sheet = |transform_index: usize, edge_index: usize| graph.edges_vertices[edge_index].iter().all(|&vertex_index| {
+		is_in_bounds(&transforms[transform_index].apply(&Point::from_coordinates(&graph.vertices_coordinates[vertex_index])), bounds)
+	});
+
+	let mut violations: Vec<SymmetryViolation> = Vec::new();
+	let mut mismatches: HashSet<(usize, usize)> = HashSet::new();
+	for edge_index in 0..graph.edges_vertices.len() {
+		for (transform_index, transform) in transforms.iter().enumerate() {
+			match image_edge(graph, index, &edges_map, transform, edge_index) {
+				None => if assignment(edge_index) != EdgeAssignment::Boundary && is_image_on_sheet(transform_index, edge_index) {
+					violations.push(SymmetryViolation::MissingImage(edge_index, transform_index));
+				},
+				Some(image_index) => {
+					if image_index <= edge_index || assignment(image_index) == assignment(edge_index) { continue; }
+					if !mismatches.insert((edge_index, image_index)) { continue; }
+					violations.push(SymmetryViolation::AssignmentMismatch(edge_index, image_index, assignment(edge_index), assignment(image_index)));
+				},
+			}
+		}
+	}
+	return violations;
+}
\ No newline at end of file
@@ -0,0 +1,222 @@
+use serde::{Serialize, Deserialize};
+
+use crate::fold::Graph;
+use crate::geometry::Point;
+use crate::graph::snap::ray_direction;
+use crate::real::Real;
+
+pub const SYMMETRY_KEY: &str = "libfold:symmetry";
+pub const SYMMETRY_TOLERANCE: f64 = 1e-6;
+
+// Groups generated by too many symmetries are cut off rather than enumerated
+const MAX_TRANSFORMS: usize = 64;
+
+#[derive(Default, Clone, Copy, Serialize, Deserialize, Debug, PartialEq)]
+pub enum SymmetryType {
+	#[default]
+	#[serde(rename = "mirror")]
+	Mirror,
+	#[serde(rename = "rotation")]
+	Rotation,
+	#[serde(rename = "glide")]
+	Glide,
+}
+
+// Mirrors and glides reflect across the line through origin along vector, glides then move by the whole vector,
+// rotations turn around origin by a fraction 1/order of a full turn
+#[derive(Clone, Serialize, Deserialize, Debug)]
+#[serde(default)]
+pub struct Symmetry {
+	#[serde(rename = "type")]
+	pub symmetry_type: SymmetryType,
+
+	pub origin: Vec<Real>,
+
+	#[serde(skip_serializing_if = "Vec::is_empty")]
+	pub vector: Vec<Real>,
+
+	#[serde(skip_serializing_if = "is_zero")]
+	pub order: usize,
+}
+
+fn is_zero(order: &usize) -> bool {
+	return *order == 0;
+}
+
+impl Default for Symmetry {
+	fn default() -> Self {
+		return Self {
+			symmetry_type: Default::default(),
+			origin: vec![Real::from_f64(0.0), Real::from_f64(0.0)],
+			vector: Default::default(),
+			order: Default::default(),
+		}
+	}
+}
+
+impl Symmetry {
+	pub fn mirror(origin: &Point, vector: &Point) -> Self {
+		return Self { symmetry_type: SymmetryType::Mirror, origin: origin.to_coordinates(), vector: vector.to_coordinates(), order: 0 }
+	}
+
+	pub fn rotation(origin: &Point, order: usize) -> Self {
+		return Self { symmetry_type: SymmetryType::Rotation, origin: origin.to_coordinates(), vector: Vec::new(), order }
+	}
+
+	pub fn glide(origin: &Point, vector: &Point) -> Self {
+		return Self { symmetry_type: SymmetryType::Glide, origin: origin.to_coordinates(), vector: vector.to_coordinates(), order: 0 }
+	}
+
+	pub fn transform(&self) -> Option<SymmetryTransform> {
+		let origin = Point::from_coordinates(&self.origin);
+		let vector = Point::from_coordinates(&self.vector);
+		return match self.symmetry_type {
+			SymmetryType::Mirror if !vector.is_zero() => Some(SymmetryTransform::reflection(&origin, &vector)),
+			SymmetryType::Glide if !vector.is_zero() => Some(SymmetryTransform::reflection(&origin, &vector).then_translate(&vector)),
+			SymmetryType::Rotation if self.order > 1 => Some(SymmetryTransform::rotation(&origin, self.order)),
+			_ => None,
+		};
+	}
+
+	// The same symmetry on a sheet moved by the transform, which has to be a similarity
+	pub fn transformed(&self, transform: &SymmetryTransform) -> Self {
+		let origin = Point::from_coordinates(&self.origin);
+		let image_origin = transform.apply(&origin);
+		let vector = match self.vector.len() {
+			0 => Vec::new(),
+			_ => (&transform.apply(&(&origin + &Point::from_coordinates(&self.vector))) - &image_origin).to_coordinates(),
+		};
+		return Self { origin: image_origin.to_coordinates(), vector, ..self.clone() }
+	}
+}
+
+// x' = a x + b y + c, y' = d x + e y + f
+#[derive(Clone, Debug)]
+pub struct SymmetryTransform {
+	pub matrix: [Real; 6],
+}
+
+impl SymmetryTransform {
+	pub fn identity() -> Self {
+		let (one, zero) = (|| Real::from_f64(1.0), || Real::from_f64(0.0));
+		return Self { matrix: [one(), zero(), zero(), zero(), one(), zero()] }
+	}
+
+	// Built from the unnormalized vector to stay exact
+	fn reflection(origin: &Point, vector: &Point) -> Self {
+		let length_squared = vector.length_squared();
+		let cos = (&vector.x * &vector.x - &vector.y * &vector.y) / &length_squared;
+		let sin = Real::from_f64(2.0) * &vector.x * &vector.y / &length_squared;
+		return Self::linear_around(origin, [cos.clone(), sin.clone(), sin, -cos]);
+	}
+
+	fn rotation(origin: &Point, order: usize) -> Self {
+		let direction = ray_direction(1, order);
+		return Self::linear_around(origin, [direction.x.clone(), -&direction.y, direction.y, direction.x]);
+	}
+
+	fn linear_around(origin: &Point, [a, b, d, e]: [Real; 4]) -> Self {
+		let c = &origin.x - &(&a * &origin.x + &b * &origin.y);
+		let f = &origin.y - &(&d * &origin.x + &e * &origin.y);
+		return Self { matrix: [a, b, c, d, e, f] }
+	}
+
+	fn then_translate(mut self, vector: &Point) -> Self {
+		self.matrix[2] = &self.matrix[2] + &vector.x;
+		self.matrix[5] = &self.matrix[5] + &vector.y;
+		return self;
+	}
+
+	pub fn scale(factor: &Real) -> Self {
+		let zero = || Real::from_f64(0.0);
+		return Self { matrix: [factor.clone(), zero(), zero(), zero(), factor.clone(), zero()] }
+	}
+
+	// The part of a homogeneous matrix of size 3 or 4 acting on the xy plane,
+	// when the plane is kept and the transform is affine on it
+	pub fn from_homogeneous(matrix: &[Vec<Real>]) -> Option<Self> {
+		let size = matrix.len();
+		if !(size == 3 || size == 4) || matrix.iter().any(|row| row.len() != size) { return None; }
+		let w = &matrix[size - 1][size - 1];
+		if *w == 0.0 { return None; }
+		if matrix[2..].iter().any(|row| row[0] != 0.0 || row[1] != 0.0) { return None; }
+		if size == 4 && (matrix[0][2] != 0.0 || matrix[1][2] != 0.0 || matrix[3][2] != 0.0) { return None; }
+		let entry = |row: usize, column: usize| &matrix[row][column] / w;
+		return Some(Self { matrix: [
+			entry(0, 0), entry(0, 1), entry(0, size - 1),
+			entry(1, 0), entry(1, 1), entry(1, size - 1),
+		] })
+	}
+
+	// Only similarities map mirrors, rotations and glides onto symmetries of the same type
+	pub fn is_similarity(&self) -> bool {
+		let [a, b, _, d, e, _] = &self.matrix;
+		let is_degenerate = *a == 0.0 && *b == 0.0;
+		return !is_degenerate && ((a == e && *b == -d) || (*a == -e && b == d));
+	}
+
+	pub fn apply(&self, point: &Point) -> Point {
+		let [a, b, c, d, e, f] = &self.matrix;
+		return Point::new(a * &point.x + b * &point.y + c, d * &point.x + e * &point.y + f);
+	}
+
+	// The transform applying other first, then self
+	pub fn compose(&self, other: &Self) -> Self {
+		let [a, b, c, d, e, f] = &self.matrix;
+		let [g, h, i, j, k, l] = &other.matrix;
+		return Self { matrix: [
+			a * g + b * j, a * h + b * k, a * i + b * l + c,
+			d * g + e * j, d * h + e * k, d * i + e * l + f,
+		] }
+	}
+
+	fn is_near(&self, other: &Self) -> bool {
+		return self.matrix.iter().zip(other.matrix.iter()).all(|(a, b)| (a.to_f64() - b.to_f64()).abs() <= SYMMETRY_TOLERANCE);
+	}
+}
+
+pub fn graph_bounds(graph: &Graph) -> Option<([f64; 2], [f64; 2])> {
+	if graph.vertices_coordinates.len() == 0 { return None; }
+	let (mut min, mut max) = ([f64::INFINITY; 2], [f64::NEG_INFINITY; 2]);
+	for coordinates in graph.vertices_coordinates.iter() {
+		let point = Point::from_coordinates(coordinates);
+		for (axis, value) in [point.x.to_f64(), point.y.to_f64()].into_iter().enumerate() {
+			min[axis] = min[axis].min(value);
+			max[axis] = max[axis].max(value);
+		}
+	}
+	return Some((min, max));
+}
+
+pub fn is_in_bounds(point: &Point, (min, max): ([f64; 2], [f64; 2])) -> bool {
+	let (x, y) = (point.x.to_f64(), point.y.to_f64());
+	return x >= min[0] - SYMMETRY_TOLERANCE && x <= max[0] + SYMMETRY_TOLERANCE
+		&& y >= min[1] - SYMMETRY_TOLERANCE && y <= max[1] + SYMMETRY_TOLERANCE;
+}
+
+// Every element of the group but the identity, glides generate infinitely many so only those
+// keeping the center of the graph on the sheet are listed, glides along with their inverse
+pub fn symmetry_transforms(graph: &Graph, symmetries: &[Symmetry]) -> Vec<SymmetryTransform> {
+	let mut generators: Vec<SymmetryTransform> = symmetries.iter().filter_map(|symmetry| symmetry.transform()).collect();
+	for symmetry in symmetries.iter().filter(|symmetry| symmetry.symmetry_type == SymmetryType::Glide) {
+		let inverse = Symmetry { vector: symmetry.vector.iter().map(|value| -value).collect(), ..symmetry.clone() };
+		generators.extend(inverse.transform());
+	}
+	let Some(bounds) = graph_bounds(graph) else { return Vec::new(); };
+	let center = Point::from_f64((bounds.0[0] + bounds.1[0]) / 2.0, (bounds.0[1] + bounds.1[1]) / 2.0);
+
+	let mut transforms: Vec<SymmetryTransform> = vec![SymmetryTransform::identity()];
+	let mut next_index = 0;
+	while next_index < transforms.len() && transforms.len() < MAX_TRANSFORMS {
+		let transform = transforms[next_index].clone();
+		next_index += 1;
+		for generator in generators.iter() {
+			let composed = generator.compose(&transform);
+			if !is_in_bounds(&composed.apply(&center), bounds) { continue; }
+			if transforms.iter().any(|other| other.is_near(&composed)) { continue; }
+			transforms.push(composed);
+		}
+	}
+	transforms.remove(0);
+	return transforms;
+}
\ No newline at end of file
@@ -0,0 +1,18 @@
+mod group;
+mod replicate;
+mod check;
+
+pub use group::Symmetry;
+pub use group::SymmetryType;
+pub use group::SymmetryTransform;
+pub use group::SYMMETRY_KEY;
+pub use group::SYMMETRY_TOLERANCE;
+pub use group::symmetry_transforms;
+
+pub use replicate::symmetric_edges;
+pub use replicate::symmetric_edges_with_index;
+pub use replicate::insert_symmetric_segment;
+
+pub use check::SymmetryViolation;
+pub use check::check_symmetry;
+pub use check::check_symmetry_with_index;
\ No newline at end of file
@@ -0,0 +1,74 @@
+use std::collections::HashMap;
+
+use crate::fold::{Graph, EdgeAssignment};
+use crate::geometry::Point;
+use crate::graph::arrangement::insert_segment;
+use crate::graph::spatial::SpatialIndex;
+use super::group::{Symmetry, SymmetryTransform, SYMMETRY_TOLERANCE, symmetry_transforms, graph_bounds, is_in_bounds};
+
+fn vertex_point(graph: &Graph, vertex_index: usize) -> Point {
+	return Point::from_coordinates(&graph.vertices_coordinates[vertex_index]);
+}
+
+fn nearest_vertex(graph: &Graph, index: &SpatialIndex, point: &Point) -> Option<usize> {
+	let vertex_index = index.nearest_vertex(point)?;
+	if (&vertex_point(graph, vertex_index) - point).length().to_f64() > SYMMETRY_TOLERANCE { return None; }
+	return Some(vertex_index);
+}
+
+pub type EdgesMap = HashMap<(usize, usize), usize>;
+
+// Edges by their vertices, the smaller index first, so that images are found without scanning the edges
+pub fn make_edges_map(graph: &Graph) -> EdgesMap {
+	return graph.edges_vertices.iter().enumerate().rev()
+		.map(|(edge_index, edge_vertices)| ((edge_vertices[0].min(edge_vertices[1]), edge_vertices[0].max(edge_vertices[1])), edge_index))
+		.collect();
+}
+
+pub fn image_edge(graph: &Graph, index: &SpatialIndex, edges_map: &EdgesMap, transform: &SymmetryTransform, edge_index: usize) -> Option<usize> {
+	let edge_vertices = &graph.edges_vertices[edge_index];
+	let a = nearest_vertex(graph, index, &transform.apply(&vertex_point(graph, edge_vertices[0])))?;
+	let b = nearest_vertex(graph, index, &transform.apply(&vertex_point(graph, edge_vertices[1])))?;
+	return edges_map.get(&(a.min(b), a.max(b))).copied();
+}
+
+// The edge with all its images, each once
+pub fn symmetric_edges(graph: &Graph, symmetries: &[Symmetry], edge_index: usize) -> Vec<usize> {
+	if symmetries.len() == 0 { return vec![edge_index]; }
+	return symmetric_edges_with_index(graph, symmetries, &SpatialIndex::new(graph), edge_index);
+}
+
+// The index has to be up to date with the graph, like the one an edit session keeps
+pub fn symmetric_edges_with_index(graph: &Graph, symmetries: &[Symmetry], index: &SpatialIndex, edge_index: usize) -> Vec<usize> {
+	let mut edges = vec![edge_index];
+	let transforms = symmetry_transforms(graph, symmetries);
+	if transforms.len() == 0 { return edges; }
+	let edges_map = make_edges_map(graph);
+	for transform in transforms.iter() {
+		let Some(image_index) = image_edge(graph, index, &edges_map, transform, edge_index) else { continue; };
+		if !edges.contains(&image_index) { edges.push(image_index); }
+	}
+	return edges;
+}
+
+// Images falling off the sheet are left out, the new edges of all the insertions are returned
+pub fn insert_symmetric_segment(graph: &mut Graph, symmetries: &[Symmetry], a: &Point, b: &Point, assignment: EdgeAssignment) -> Vec<usize> {
+	let transforms = symmetry_transforms(graph, symmetries);
+	let bounds = graph_bounds(graph);
+	let mut segments: Vec<(Point, Point)> = vec![(a.clone(), b.clone())];
+	for transform in transforms.iter() {
+		let (image_a, image_b) = (transform.apply(a), transform.apply(b));
+		if let Some(bounds) = bounds && !(is_in_bounds(&image_a, bounds) && is_in_bounds(&image_b, bounds)) { continue; }
+		let is_near = |p: &Point, q: &Point| (p - q).length().to_f64() <= SYMMETRY_TOLERANCE;
+		if segments.iter().any(|(c, d)| (is_near(c, &image_a) && is_near(d, &image_b)) || (is_near(c, &image_b) && is_near(d, &image_a))) { continue; }
+		segments.push((image_a, image_b));
+	}
+
+	let mut edges: Vec<usize> = Vec::new();
+	for (a, b) in segments.iter() {
+		for edge_index in insert_segment(graph, a, b, assignment) {
+			if !edges.contains(&edge_index) { edges.push(edge_index); }
+		}
+	}
+	return edges;
+}
\ No newline at end of file
@@ -0,0 +1,7 @@
+#[derive(Clone, Copy, Debug)]
+pub enum TransformError {
+	InvalidMatrix,
+	PointAtInfinity(usize),
+	MixedOrientation,
+	UnknownUnit,
+}
\ No newline at end of file
@@ -0,0 +1,101 @@
+use crate::fold::{Graph, EdgeOrder};
+use crate::real::Real;
+use super::error::TransformError;
+
+fn determinant(matrix: &[Vec<Real>]) -> Real {
+	if matrix.len() == 1 { return matrix[0][0].clone(); }
+	let mut result = Real::from_f64(0.0);
+	for column in 0..matrix.len() {
+		let minor: Vec<Vec<Real>> = matrix[1..].iter()
+			.map(|row| row.iter().enumerate().filter(|(index, _)| *index != column).map(|(_, value)| value.clone()).collect())
+			.collect();
+		let term = &matrix[0][column] * &determinant(&minor);
+		result = if column % 2 == 0 { &result + &term } else { &result - &term };
+	}
+	return result;
+}
+
+fn edges_length(graph: &Graph) -> Vec<Real> {
+	return graph.edges_vertices.iter().map(|edge_vertices| {
+		let (a, b) = (&graph.vertices_coordinates[edge_vertices[0]], &graph.vertices_coordinates[edge_vertices[1]]);
+		let length_squared = a.iter().zip(b.iter()).fold(Real::from_f64(0.0), |sum, (p, q)| {
+			let difference = q - p;
+			return &sum + &(&difference * &difference);
+		});
+		return length_squared.sqrt();
+	}).collect();
+}
+
+// Lists that go around a face or a vertex counterclockwise, with the element between index i and i + 1 at i
+fn reverse_cycle<T>(list: &mut [T]) {
+	if list.len() < 2 { return; }
+	list.reverse();
+	list.rotate_left(1);
+}
+
+// Faces keep their counterclockwise winding, so their normals follow the mirrored sheet and faceOrders stay valid,
+// while edges see their left and right sides swapped
+fn reverse_orientation(graph: &mut Graph) {
+	for vertex_vertices in graph.vertices_vertices.iter_mut() { vertex_vertices.reverse(); }
+	for vertex_edges in graph.vertices_edges.iter_mut() { vertex_edges.reverse(); }
+	for vertex_faces in graph.vertices_faces.iter_mut() { reverse_cycle(vertex_faces); }
+	for face_vertices in graph.faces_vertices.iter_mut() { face_vertices.reverse(); }
+	for face_edges in graph.faces_edges.iter_mut() { reverse_cycle(face_edges); }
+	for face_faces in graph.faces_faces.iter_mut() { reverse_cycle(face_faces); }
+	for edge_faces in graph.edges_faces.iter_mut().filter(|edge_faces| edge_faces.len() == 2) { edge_faces.swap(0, 1); }
+	for (_, _, order) in graph.edge_orders.iter_mut() {
+		*order = match order {
+			EdgeOrder::Left => EdgeOrder::Right,
+			EdgeOrder::Right => EdgeOrder::Left,
+			EdgeOrder::Unknown => EdgeOrder::Unknown,
+		};
+	}
+}
+
+// Homogeneous matrices of size 3 act on x and y only, those of size 4 on x, y and z,
+// 2D vertices stay 2D unless the transform lifts them off the plane
+pub fn transform_graph(graph: &mut Graph, matrix: &[Vec<Real>]) -> Result<(), TransformError> {
+	let size = matrix.len();
+	if !(size == 3 || size == 4) || matrix.iter().any(|row| row.len() != size) { return Err(TransformError::InvalidMatrix); }
+	let dimension = size - 1;
+	let (zero, one) = (Real::from_f64(0.0), Real::from_f64(1.0));
+
+	let determinant = determinant(matrix);
+	let mut is_reversed: Option<bool> = None;
+	let mut vertices_coordinates: Vec<Vec<Real>> = Vec::with_capacity(graph.vertices_coordinates.len());
+	for (vertex_index, coordinates) in graph.vertices_coordinates.iter().enumerate() {
+		let point: Vec<Real> = (0..dimension).map(|axis| coordinates.get(axis).cloned().unwrap_or(zero.clone()))
+			.chain(std::iter::once(one.clone()))
+			.collect();
+		let mut image: Vec<Real> = matrix.iter()
+			.map(|row| row.iter().zip(point.iter()).fold(zero.clone(), |sum, (a, b)| &sum + &(a * b)))
+			.collect();
+		let w = image.pop().unwrap();
+		if w == 0.0 { return Err(TransformError::PointAtInfinity(vertex_index)); }
+
+		// The orientation at a point follows the sign of det / w^size
+		let is_vertex_reversed = (determinant < 0.0) != (size % 2 == 1 && w < 0.0);
+		if is_reversed.is_some_and(|is_reversed| is_reversed != is_vertex_reversed) { return Err(TransformError::MixedOrientation); }
+		is_reversed = Some(is_vertex_reversed);
+
+		let mut transformed: Vec<Real> = image.iter().map(|value| value / &w).collect();
+		transformed.extend(coordinates.iter().skip(dimension).cloned());
+		vertices_coordinates.push(transformed);
+	}
+
+	let is_planar = graph.vertices_coordinates.iter().all(|coordinates| coordinates.len() <= 2);
+	if dimension == 3 && is_planar && vertices_coordinates.iter().all(|coordinates| coordinates[2] == 0.0) {
+		for coordinates in vertices_coordinates.iter_mut() { coordinates.truncate(2); }
+	}
+	graph.vertices_coordinates = vertices_coordinates;
+	if graph.edges_length.len() > 0 { graph.edges_length = edges_length(graph); }
+	if is_reversed == Some(true) { reverse_orientation(graph); }
+	return Ok(());
+}
+
+pub fn scale_graph(graph: &mut Graph, factor: &Real) {
+	for coordinates in graph.vertices_coordinates.iter_mut() {
+		for value in coordinates.iter_mut() { *value = &*value * factor; }
+	}
+	for length in graph.edges_length.iter_mut() { *length = &*length * &factor.abs(); }
+}
\ No newline at end of file
@@ -0,0 +1,11 @@
+mod error;
+mod matrix;
+mod unit;
+
+pub use error::TransformError;
+
+pub use matrix::transform_graph;
+pub use matrix::scale_graph;
+
+pub use unit::unit_meters;
+pub use unit::unit_scale;
\ No newline at end of file
@@ -0,0 +1,21 @@
+use crate::fold::FrameUnit;
+use crate::real::Real;
+
+pub fn unit_meters(unit: FrameUnit) -> Option<Real> {
+	let inch = Real::from_str("0.0254");
+	return match unit {
+		FrameUnit::Unit => None,
+		FrameUnit::PostScriptPoints => Some(&inch / &Real::from_f64(72.0)),
+		FrameUnit::Inch => Some(inch),
+		FrameUnit::Meters => Some(Real::from_f64(1.0)),
+		FrameUnit::Centimeters => Some(Real::from_str("0.01")),
+		FrameUnit::Millimeters => Some(Real::from_str("0.001")),
+		FrameUnit::Microns => Some(Real::from_str("1e-6")),
+		FrameUnit::Nanometers => Some(Real::from_str("1e-9")),
+	};
+}
+
+// Factor turning lengths in from into lengths in to
+pub fn unit_scale(from: FrameUnit, to: FrameUnit) -> Option<Real> {
+	return Some(&unit_meters(from)? / &unit_meters(to)?);
+}
\ No newline at end of file
@@ -1,3 +1,7 @@
+pub mod edit;
 pub mod fold;
+pub mod format;
+pub mod geometry;
 pub mod graph;
-pub mod real;
\ No newline at end of file
+pub mod real;
+pub mod simulation;
\ No newline at end of file
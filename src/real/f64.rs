@@ -1,5 +1,6 @@
 use serde::{Serialize, Deserialize};
 use core::cmp::{PartialEq, PartialOrd, Ordering};
+use core::ops::{Add, Sub, Mul, Div, Neg};
 
 #[derive(Default, Clone, Serialize, Deserialize, Debug)]
 pub struct Real(f64);
@@ -12,11 +13,94 @@ impl Real {
 	pub fn to_string(&self) -> String {
 		return self.0.to_string();
 	}
+
+	pub fn from_f64(value: f64) -> Self {
+		return Real(value);
+	}
+
+	pub fn to_f64(&self) -> f64 {
+		return self.0;
+	}
+
+	pub fn pi() -> Self {
+		return Real(std::f64::consts::PI);
+	}
+
+	pub fn abs(&self) -> Self {
+		return Real(self.0.abs());
+	}
+
+	pub fn sqrt(&self) -> Self {
+		return Real(self.0.sqrt());
+	}
+
+	pub fn cbrt(&self) -> Self {
+		return Real(self.0.cbrt());
+	}
+
+	pub fn cos(&self) -> Self {
+		return Real(self.0.cos());
+	}
+
+	pub fn acos(&self) -> Self {
+		return Real(self.0.acos());
+	}
+}
+
+impl PartialEq for Real {
+	fn eq(&self, other: &Real) -> bool {
+		return f64::eq(&self.0, &other.0);
+	}
+}
+
+impl PartialOrd for Real {
+	fn partial_cmp(&self, other: &Real) -> Option<Ordering> {
+		return f64::partial_cmp(&self.0, &other.0);
+	}
+}
+
+macro_rules! operator {
+	($trait: ident, $method: ident, $operator: tt) => {
+		impl $trait<&Real> for &Real {
+			type Output = Real;
+			fn $method(self, other: &Real) -> Real { return Real(self.0 $operator other.0); }
+		}
+
+		impl $trait<Real> for &Real {
+			type Output = Real;
+			fn $method(self, other: Real) -> Real { return self $operator &other; }
+		}
+
+		impl $trait<&Real> for Real {
+			type Output = Real;
+			fn $method(self, other: &Real) -> Real { return &self $operator other; }
+		}
+
+		impl $trait<Real> for Real {
+			type Output = Real;
+			fn $method(self, other: Real) -> Real { return &self $operator &other; }
+		}
+	};
+}
+
+operator!(Add, add, +);
+operator!(Sub, sub, -);
+operator!(Mul, mul, *);
+operator!(Div, div, /);
+
+impl Neg for &Real {
+	type Output = Real;
+	fn neg(self) -> Real { return Real(-self.0); }
+}
+
+impl Neg for Real {
+	type Output = Real;
+	fn neg(self) -> Real { return -&self; }
 }
 
 impl PartialEq<f64> for Real {
 	fn eq(&self, other: &f64) -> bool {
-		return f64::eq(self.0, other);
+		return f64::eq(&self.0, other);
 	}
 
 	fn ne(&self, other: &f64) -> bool {
@@ -26,22 +110,22 @@ impl PartialEq<f64> for Real {
 
 impl PartialOrd<f64> for Real {
 	fn partial_cmp(&self, other: &f64) -> Option<Ordering> {
-		return Float::partial_cmp(&self.0, other);
+		return f64::partial_cmp(&self.0, other);
 	}
 
 	fn lt(&self, other: &f64) -> bool {
-		return Float::lt(&self.0, other);
+		return f64::lt(&self.0, other);
 	}
 
 	fn le(&self, other: &f64) -> bool {
-		return Float::le(&self.0, other);
+		return f64::le(&self.0, other);
 	}
 
 	fn gt(&self, other: &f64) -> bool {
-		return Float::gt(&self.0, other);
+		return f64::gt(&self.0, other);
 	}
 
 	fn ge(&self, other: &f64) -> bool {
-		return Float::ge(&self.0, other);
+		return f64::ge(&self.0, other);
 	}
 }
\ No newline at end of file
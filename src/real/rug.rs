@@ -1,7 +1,9 @@
 use serde::{Serialize, Serializer, Deserialize, Deserializer};
+use serde::de::{self, Visitor};
 use core::cmp::{PartialEq, PartialOrd, Ordering};
-use serde_json::Number;
-use rug::Float;
+use core::ops::{Add, Sub, Mul, Div, Neg};
+use rug::{Float, Rational};
+use rug::float::Constant;
 
 #[derive(Clone, Debug)]
 pub struct Real(Float, u64);
@@ -12,10 +14,37 @@ impl Serialize for Real {
 	}
 }
 
+// Reals are written as strings to keep every bit, but plain JSON numbers are read as well
+struct RealVisitor;
+
+impl<'de> Visitor<'de> for RealVisitor {
+	type Value = Real;
+
+	fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+		return formatter.write_str("a number or a numeric string");
+	}
+
+	fn visit_i64<E: de::Error>(self, value: i64) -> Result<Real, E> {
+		return Ok(Real::from_str(&value.to_string()));
+	}
+
+	fn visit_u64<E: de::Error>(self, value: u64) -> Result<Real, E> {
+		return Ok(Real::from_str(&value.to_string()));
+	}
+
+	fn visit_f64<E: de::Error>(self, value: f64) -> Result<Real, E> {
+		return Ok(Real::from_str(&value.to_string()));
+	}
+
+	fn visit_str<E: de::Error>(self, value: &str) -> Result<Real, E> {
+		let result = Float::parse(value).map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))?;
+		return Ok(Real(Float::with_val_64(256, result), 256));
+	}
+}
+
 impl<'de> Deserialize<'de> for Real {
 	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-		let number = Number::deserialize(deserializer)?;
-		return Ok(Real::from_str(&number.to_string()));
+		return deserializer.deserialize_any(RealVisitor);
 	}
 }
 
@@ -28,6 +57,98 @@ impl Real {
 	pub fn to_string(&self) -> String {
 		return Float::to_string(&self.0);
 	}
+
+	pub fn from_f64(value: f64) -> Self {
+		return Real(Float::with_val_64(256, value), 256);
+	}
+
+	pub fn to_f64(&self) -> f64 {
+		return Float::to_f64(&self.0);
+	}
+
+	pub fn to_rational(&self) -> Rational {
+		return Float::to_rational(&self.0).unwrap_or_default();
+	}
+
+	pub fn pi() -> Self {
+		return Real(Float::with_val_64(256, Constant::Pi), 256);
+	}
+
+	pub fn abs(&self) -> Self {
+		return Real(Float::with_val_64(self.1, self.0.abs_ref()), self.1);
+	}
+
+	pub fn sqrt(&self) -> Self {
+		return Real(Float::with_val_64(self.1, self.0.sqrt_ref()), self.1);
+	}
+
+	pub fn cbrt(&self) -> Self {
+		return Real(Float::with_val_64(self.1, self.0.cbrt_ref()), self.1);
+	}
+
+	pub fn cos(&self) -> Self {
+		return Real(Float::with_val_64(self.1, self.0.cos_ref()), self.1);
+	}
+
+	pub fn acos(&self) -> Self {
+		return Real(Float::with_val_64(self.1, self.0.acos_ref()), self.1);
+	}
+}
+
+impl PartialEq for Real {
+	fn eq(&self, other: &Real) -> bool {
+		return Float::eq(&self.0, &other.0);
+	}
+}
+
+impl PartialOrd for Real {
+	fn partial_cmp(&self, other: &Real) -> Option<Ordering> {
+		return Float::partial_cmp(&self.0, &other.0);
+	}
+}
+
+macro_rules! operator {
+	($trait: ident, $method: ident, $operator: tt) => {
+		impl $trait<&Real> for &Real {
+			type Output = Real;
+			fn $method(self, other: &Real) -> Real {
+				let precision = u64::max(self.1, other.1);
+				return Real(Float::with_val_64(precision, &self.0 $operator &other.0), precision);
+			}
+		}
+
+		impl $trait<Real> for &Real {
+			type Output = Real;
+			fn $method(self, other: Real) -> Real { return self $operator &other; }
+		}
+
+		impl $trait<&Real> for Real {
+			type Output = Real;
+			fn $method(self, other: &Real) -> Real { return &self $operator other; }
+		}
+
+		impl $trait<Real> for Real {
+			type Output = Real;
+			fn $method(self, other: Real) -> Real { return &self $operator &other; }
+		}
+	};
+}
+
+operator!(Add, add, +);
+operator!(Sub, sub, -);
+operator!(Mul, mul, *);
+operator!(Div, div, /);
+
+impl Neg for &Real {
+	type Output = Real;
+	fn neg(self) -> Real {
+		return Real(Float::with_val_64(self.1, -&self.0), self.1);
+	}
+}
+
+impl Neg for Real {
+	type Output = Real;
+	fn neg(self) -> Real { return -&self; }
 }
 
 impl PartialEq<f64> for Real {
@@ -0,0 +1,31 @@
+use crate::fold::{Fold, Graph};
+use super::model::SimulationError;
+use super::parameters::Parameters;
+use super::simulator::Simulator;
+
+pub fn get_frame_graph(fold: &Fold, frame_index: usize) -> Result<Graph, SimulationError> {
+	return match fold.get_frame(frame_index) {
+		Some(frame) => match fold.get_inherited_frame(frame_index) {
+			Ok(inherited_frame) => Ok(inherited_frame.graph),
+			Err(_) => Ok(frame.graph.clone()),
+		},
+		None => Err(SimulationError::MissingFrame(frame_index)),
+	};
+}
+
+pub fn simulate_frames(fold: &mut Fold, frame_index: usize, fold_percentages: &[f64], parameters: Parameters) -> Result<Vec<usize>, SimulationError> {
+	let graph = get_frame_graph(fold, frame_index)?;
+
+	let mut simulator = Simulator::new(&graph, parameters)?;
+	let mut frame_indices: Vec<usize> = Vec::new();
+	for &fold_percentage in fold_percentages.iter() {
+		simulator.set_fold_percentage(fold_percentage);
+		simulator.solve();
+		fold.frames.push(simulator.make_frame(frame_index));
+		frame_indices.push(fold.frames.len());
+	}
+
+	let animation = String::from("animation");
+	if !fold.classes.contains(&animation) { fold.classes.push(animation); }
+	return Ok(frame_indices);
+}
\ No newline at end of file
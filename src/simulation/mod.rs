@@ -0,0 +1,24 @@
+mod vector;
+mod parameters;
+mod model;
+mod simulator;
+mod frames;
+mod sequence;
+
+pub use parameters::Parameters;
+
+pub use model::Model;
+pub use model::Bar;
+pub use model::Hinge;
+pub use model::HingeType;
+pub use model::SimulationError;
+
+pub use simulator::Simulator;
+
+pub use frames::get_frame_graph;
+pub use frames::simulate_frames;
+
+pub use sequence::Sequence;
+pub use sequence::Step;
+pub use sequence::StepType;
+pub use sequence::STEP_KEY;
\ No newline at end of file
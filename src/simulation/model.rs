@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+
+use crate::fold::{Graph, EdgeAssignment, Error};
+use crate::graph::make::{make_cyclical_pairs_map, make_faces_triangles};
+use super::vector;
+
+#[derive(Clone, Copy, Debug)]
+pub enum SimulationError {
+	MissingCoordinates,
+	MissingFaces,
+	MissingFrame(usize),
+	GraphError(Error),
+}
+
+impl From<Error> for SimulationError {
+	fn from(error: Error) -> Self { SimulationError::GraphError(error) }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Bar {
+	pub vertices: (usize, usize),
+	pub length: f64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HingeType {
+	Crease,
+	Facet,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Hinge {
+	// Edge vertices followed by the flap vertices of the left and right triangles
+	pub vertices: [usize; 4],
+	pub edge: Option<usize>,
+	pub length: f64,
+	pub fold_angle: f64,
+	pub hinge_type: HingeType,
+}
+
+#[derive(Clone, Debug)]
+pub struct Model {
+	pub vertices_coordinates: Vec<[f64; 3]>,
+	pub triangles: Vec<[usize; 3]>,
+	pub triangles_face: Vec<usize>,
+	pub bars: Vec<Bar>,
+	pub hinges: Vec<Hinge>,
+}
+
+pub fn edge_fold_angle(graph: &Graph, edge_index: usize) -> Option<f64> {
+	if let Some(fold_angle) = graph.edges_fold_angle.get(edge_index) {
+		return Some(fold_angle.to_f64().to_radians());
+	}
+	return match graph.edges_assignment.get(edge_index) {
+		Some(EdgeAssignment::Mountain) => Some(-std::f64::consts::PI),
+		Some(EdgeAssignment::Valley) => Some(std::f64::consts::PI),
+		Some(EdgeAssignment::Boundary) => None,
+		Some(EdgeAssignment::Cut) => None,
+		_ => Some(0.0),
+	};
+}
+
+impl Model {
+	pub fn new(graph: &Graph) -> Result<Self, SimulationError> {
+		if graph.vertices_coordinates.len() == 0 { return Err(SimulationError::MissingCoordinates); }
+		if graph.faces_vertices.len() == 0 { return Err(SimulationError::MissingFaces); }
+		graph.validate()?;
+
+		let mut vertices_coordinates: Vec<[f64; 3]> = Vec::new();
+		for vertex_coordinates in graph.vertices_coordinates.iter() {
+			let mut point = [0.0; 3];
+			for (axis, value) in vertex_coordinates.iter().take(3).enumerate() {
+				point[axis] = value.to_f64();
+			}
+			vertices_coordinates.push(point);
+		}
+
+		let mut triangles: Vec<[usize; 3]> = Vec::new();
+		let mut triangles_face: Vec<usize> = Vec::new();
+		for (face_index, face_triangles) in make_faces_triangles(graph).into_iter().enumerate() {
+			for triangle in face_triangles {
+				triangles.push(triangle);
+				triangles_face.push(face_index);
+			}
+		}
+
+		let triangles_vertices: Vec<Vec<usize>> = triangles.iter().map(|t| t.to_vec()).collect();
+		let pairs_map = make_cyclical_pairs_map(&triangles_vertices);
+
+		let mut edges_map: HashMap<(usize, usize), usize> = HashMap::new();
+		for (edge_index, edge_vertices) in graph.edges_vertices.iter().enumerate() {
+			edges_map.insert((edge_vertices[0], edge_vertices[1]), edge_index);
+			edges_map.insert((edge_vertices[1], edge_vertices[0]), edge_index);
+		}
+
+		let mut model = Self {
+			vertices_coordinates,
+			triangles,
+			triangles_face,
+			bars: Vec::new(),
+			hinges: Vec::new(),
+		};
+
+		let mut pairs: Vec<(usize, usize)> = pairs_map.keys().copied().collect();
+		pairs.sort();
+		for (a, b) in pairs {
+			let left_triangle = pairs_map[&(a, b)];
+			let right_triangle_option = pairs_map.get(&(b, a));
+			if right_triangle_option.is_some() && a > b { continue; }
+
+			let length = vector::length(vector::sub(
+				model.vertices_coordinates[b],
+				model.vertices_coordinates[a],
+			));
+			model.bars.push(Bar { vertices: (a, b), length });
+
+			let right_triangle = match right_triangle_option {
+				Some(&right_triangle) => right_triangle,
+				None => continue,
+			};
+
+			let edge = edges_map.get(&(a, b)).copied();
+			let (fold_angle, hinge_type) = match edge {
+				Some(edge_index) => match edge_fold_angle(graph, edge_index) {
+					Some(fold_angle) => (fold_angle, HingeType::Crease),
+					None => continue,
+				},
+				None => (0.0, HingeType::Facet),
+			};
+
+			let left_flap = model.triangles[left_triangle].iter()
+				.copied().find(|&v| v != a && v != b).unwrap();
+			let right_flap = model.triangles[right_triangle].iter()
+				.copied().find(|&v| v != a && v != b).unwrap();
+
+			model.hinges.push(Hinge {
+				vertices: [a, b, left_flap, right_flap],
+				edge,
+				length,
+				fold_angle,
+				hinge_type,
+			});
+		}
+
+		return Ok(model);
+	}
+}
\ No newline at end of file
@@ -0,0 +1,29 @@
+#[derive(Clone, Copy, Debug)]
+pub struct Parameters {
+	pub axial_stiffness: f64,
+	pub crease_stiffness: f64,
+	pub flat_stiffness: f64,
+	pub damping_ratio: f64,
+	pub tolerance: f64,
+	pub max_steps: usize,
+}
+
+impl Default for Parameters {
+	fn default() -> Self {
+		// Origami Simulator defaults
+		return Self {
+			axial_stiffness: 20.0,
+			crease_stiffness: 0.7,
+			flat_stiffness: 0.7,
+			damping_ratio: 0.45,
+			tolerance: 1e-6,
+			max_steps: 100000,
+		}
+	}
+}
+
+impl Parameters {
+	pub fn new() -> Self {
+		return Self { ..Default::default() }
+	}
+}
\ No newline at end of file
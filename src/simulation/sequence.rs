@@ -0,0 +1,196 @@
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use std::f64::consts::PI;
+
+use crate::fold::{Fold, Graph};
+use super::frames::get_frame_graph;
+use super::model::{SimulationError, edge_fold_angle};
+use super::parameters::Parameters;
+use super::simulator::Simulator;
+
+pub const STEP_KEY: &str = "libfold:step";
+
+#[derive(Default, Clone, Copy, Serialize, Deserialize, Debug, PartialEq)]
+pub enum StepType {
+	#[default]
+	#[serde(rename = "fold")]
+	Fold,
+	#[serde(rename = "valley")]
+	Valley,
+	#[serde(rename = "mountain")]
+	Mountain,
+	#[serde(rename = "reverse")]
+	Reverse,
+	#[serde(rename = "squash")]
+	Squash,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+#[serde(default)]
+pub struct Step {
+	#[serde(rename = "type")]
+	pub step_type: StepType,
+
+	pub edges: Vec<usize>,
+
+	#[serde(skip_serializing_if = "Vec::is_empty")]
+	pub unfold_edges: Vec<usize>,
+
+	pub fold_percentage: f64,
+
+	#[serde(skip)]
+	pub title: String,
+
+	#[serde(skip)]
+	pub description: String,
+}
+
+impl Default for Step {
+	fn default() -> Self {
+		return Self {
+			step_type: Default::default(),
+			edges: Default::default(),
+			unfold_edges: Default::default(),
+			fold_percentage: 1.0,
+			title: Default::default(),
+			description: Default::default(),
+		}
+	}
+}
+
+impl Step {
+	pub fn new(step_type: StepType, edges: Vec<usize>) -> Self {
+		return Self { step_type, edges, ..Default::default() }
+	}
+
+	// A squash opens its unfolded edges (the spine of the flap) along with folding its edges,
+	// other steps unfold them at once
+	pub fn apply(&self, graph: &Graph, edges_fold_angle: &mut Vec<f64>) {
+		edges_fold_angle.resize(graph.edges_vertices.len(), 0.0);
+		for &edge_index in self.edges.iter() {
+			if edge_index >= edges_fold_angle.len() { continue; }
+			let assigned_angle = edge_fold_angle(graph, edge_index).unwrap_or(0.0);
+			edges_fold_angle[edge_index] = match self.step_type {
+				StepType::Fold => self.fold_percentage * assigned_angle,
+				StepType::Squash => self.fold_percentage * assigned_angle,
+				StepType::Valley => self.fold_percentage * PI,
+				StepType::Mountain => -self.fold_percentage * PI,
+				StepType::Reverse => if edges_fold_angle[edge_index] == 0.0 {
+					-self.fold_percentage * assigned_angle
+				} else {
+					-edges_fold_angle[edge_index]
+				},
+			};
+		}
+		for &edge_index in self.unfold_edges.iter() {
+			if edge_index >= edges_fold_angle.len() { continue; }
+			edges_fold_angle[edge_index] = match self.step_type {
+				StepType::Squash => (1.0 - self.fold_percentage) * edges_fold_angle[edge_index],
+				_ => 0.0,
+			};
+		}
+	}
+}
+
+#[derive(Clone, Debug)]
+pub struct Sequence {
+	pub frame_index: usize,
+	pub parameters: Parameters,
+	pub steps: Vec<Step>,
+	pub frames: Vec<usize>,
+}
+
+impl Sequence {
+	pub fn new(frame_index: usize, parameters: Parameters) -> Self {
+		return Self { frame_index, parameters, steps: Vec::new(), frames: Vec::new() }
+	}
+
+	pub fn from_fold(fold: &Fold, frame_index: usize, parameters: Parameters) -> Self {
+		let mut sequence = Self::new(frame_index, parameters);
+		let mut parent_index = frame_index;
+		loop {
+			let child = fold.frames.iter().enumerate().find(|(_, frame)| {
+				frame.parent == Some(parent_index) && frame.custom_data.contains_key(STEP_KEY)
+			});
+			let (index, frame) = match child {
+				Some(child) => child,
+				None => break,
+			};
+			let mut step: Step = match serde_json::from_value(frame.custom_data[STEP_KEY].clone()) {
+				Ok(step) => step,
+				Err(_) => break,
+			};
+			step.title = frame.title.clone();
+			step.description = frame.description.clone();
+			sequence.steps.push(step);
+			sequence.frames.push(index + 1);
+			parent_index = index + 1;
+		}
+		return sequence;
+	}
+
+	pub fn push_step(&mut self, step: Step) {
+		self.steps.push(step);
+	}
+
+	pub fn insert_step(&mut self, step_index: usize, step: Step) {
+		self.steps.insert(step_index, step);
+	}
+
+	pub fn remove_step(&mut self, step_index: usize) -> Step {
+		return self.steps.remove(step_index);
+	}
+
+	pub fn edges_fold_angle(&self, graph: &Graph, step_index: usize) -> Vec<f64> {
+		let mut edges_fold_angle = vec![0.0; graph.edges_vertices.len()];
+		for step in self.steps.iter().take(step_index + 1) {
+			step.apply(graph, &mut edges_fold_angle);
+		}
+		return edges_fold_angle;
+	}
+
+	pub fn generate(&mut self, fold: &mut Fold) -> Result<(), SimulationError> {
+		let graph = get_frame_graph(fold, self.frame_index)?;
+		let mut simulator = Simulator::new(&graph, self.parameters)?;
+		simulator.set_fold_percentage(1.0);
+
+		let mut edges_fold_angle = vec![0.0; graph.edges_vertices.len()];
+		let mut parent_index = self.frame_index;
+		for (step_index, step) in self.steps.iter().enumerate() {
+			step.apply(&graph, &mut edges_fold_angle);
+			simulator.set_edges_fold_angle(&edges_fold_angle);
+			simulator.solve();
+
+			let mut frame = simulator.make_frame(parent_index);
+			frame.title = step.title.clone();
+			frame.description = step.description.clone();
+			let step_value = serde_json::to_value(step).unwrap_or(Value::Null);
+			frame.custom_data.insert(String::from(STEP_KEY), step_value);
+
+			if step_index < self.frames.len() {
+				// Frames may have been removed or moved since the sequence was read
+				let frame_index = self.frames[step_index];
+				if frame_index == 0 { return Err(SimulationError::MissingFrame(frame_index)); }
+				let step_frame = fold.get_frame_mut(frame_index).ok_or(SimulationError::MissingFrame(frame_index))?;
+				*step_frame = frame;
+			} else {
+				fold.frames.push(frame);
+				self.frames.push(fold.frames.len());
+			}
+			parent_index = self.frames[step_index];
+		}
+
+		while self.frames.len() > self.steps.len() {
+			let frame_index = self.frames.pop().unwrap();
+			fold.remove_frame(frame_index);
+			for index in self.frames.iter_mut() {
+				if *index > frame_index { *index -= 1; }
+			}
+			if self.frame_index > frame_index { self.frame_index -= 1; }
+		}
+
+		let diagrams = String::from("diagrams");
+		if !fold.classes.contains(&diagrams) { fold.classes.push(diagrams); }
+		return Ok(());
+	}
+}
\ No newline at end of file
@@ -0,0 +1,188 @@
+use crate::fold::{Frame, Graph};
+use crate::real::Real;
+use super::model::{Model, HingeType, SimulationError};
+use super::parameters::Parameters;
+use super::vector;
+
+#[derive(Clone, Debug)]
+pub struct Simulator {
+	pub parameters: Parameters,
+	pub model: Model,
+	pub fold_percentage: f64,
+	positions: Vec<[f64; 3]>,
+	velocities: Vec<[f64; 3]>,
+	angles: Vec<f64>,
+}
+
+impl Simulator {
+	pub fn new(graph: &Graph, parameters: Parameters) -> Result<Self, SimulationError> {
+		let model = Model::new(graph)?;
+		let positions = model.vertices_coordinates.clone();
+		let velocities = vec![[0.0; 3]; positions.len()];
+		let angles = vec![0.0; model.hinges.len()];
+		return Ok(Self { parameters, model, fold_percentage: 0.0, positions, velocities, angles });
+	}
+
+	pub fn reset(&mut self) {
+		self.positions = self.model.vertices_coordinates.clone();
+		self.velocities = vec![[0.0; 3]; self.positions.len()];
+		self.angles = vec![0.0; self.model.hinges.len()];
+	}
+
+	pub fn set_fold_percentage(&mut self, fold_percentage: f64) {
+		self.fold_percentage = fold_percentage;
+	}
+
+	pub fn set_edges_fold_angle(&mut self, edges_fold_angle: &[f64]) {
+		for hinge in self.model.hinges.iter_mut() {
+			if hinge.hinge_type != HingeType::Crease { continue; }
+			match hinge.edge.and_then(|edge_index| edges_fold_angle.get(edge_index)) {
+				Some(&fold_angle) => hinge.fold_angle = fold_angle,
+				None => continue,
+			}
+		}
+	}
+
+	pub fn time_step(&self) -> f64 {
+		let mut max_stiffness: f64 = 0.0;
+		for bar in self.model.bars.iter() {
+			if bar.length <= 0.0 { continue; }
+			max_stiffness = max_stiffness.max(self.parameters.axial_stiffness / bar.length);
+		}
+		if max_stiffness == 0.0 { return 1.0; }
+		return 1.0 / (2.0 * std::f64::consts::PI * max_stiffness.sqrt());
+	}
+
+	pub fn hinge_angle(&self, hinge_index: usize) -> f64 {
+		return self.angles[hinge_index];
+	}
+
+	fn measure_angle(&self, vertices: [usize; 4]) -> f64 {
+		let [j, k, i, l] = vertices;
+		let edge = vector::sub(self.positions[k], self.positions[j]);
+		let left_normal = vector::cross(edge, vector::sub(self.positions[i], self.positions[j]));
+		let right_normal = vector::cross(vector::sub(self.positions[l], self.positions[j]), edge);
+		let edge_length = vector::length(edge);
+		if edge_length == 0.0 { return 0.0; }
+
+		let sine = -vector::dot(vector::cross(left_normal, right_normal), edge) / edge_length;
+		let cosine = vector::dot(left_normal, right_normal);
+		return sine.atan2(cosine);
+	}
+
+	fn update_angles(&mut self) {
+		for hinge_index in 0..self.model.hinges.len() {
+			let previous_angle = self.angles[hinge_index];
+			let mut angle = self.measure_angle(self.model.hinges[hinge_index].vertices);
+			while angle - previous_angle > std::f64::consts::PI { angle -= 2.0 * std::f64::consts::PI; }
+			while angle - previous_angle < -std::f64::consts::PI { angle += 2.0 * std::f64::consts::PI; }
+			self.angles[hinge_index] = angle;
+		}
+	}
+
+	fn forces(&self) -> Vec<[f64; 3]> {
+		let mut forces = vec![[0.0; 3]; self.positions.len()];
+
+		for bar in self.model.bars.iter() {
+			let (a, b) = bar.vertices;
+			if bar.length <= 0.0 { continue; }
+			let stiffness = self.parameters.axial_stiffness / bar.length;
+			let damping = 2.0 * self.parameters.damping_ratio * stiffness.sqrt();
+
+			let direction = vector::sub(self.positions[b], self.positions[a]);
+			let length = vector::length(direction);
+			if length == 0.0 { continue; }
+			let direction = vector::scale(direction, 1.0 / length);
+
+			let axial = vector::scale(direction, stiffness * (length - bar.length));
+			let relative_velocity = vector::sub(self.velocities[b], self.velocities[a]);
+			let force = vector::add(axial, vector::scale(relative_velocity, damping));
+			forces[a] = vector::add(forces[a], force);
+			forces[b] = vector::sub(forces[b], force);
+		}
+
+		for (hinge_index, hinge) in self.model.hinges.iter().enumerate() {
+			let [j, k, i, l] = hinge.vertices;
+			let stiffness = match hinge.hinge_type {
+				HingeType::Crease => self.parameters.crease_stiffness,
+				HingeType::Facet => self.parameters.flat_stiffness,
+			} * hinge.length;
+			let target_angle = match hinge.hinge_type {
+				HingeType::Crease => self.fold_percentage * hinge.fold_angle,
+				HingeType::Facet => hinge.fold_angle,
+			};
+			let moment = -stiffness * (self.angles[hinge_index] - target_angle);
+
+			let edge = vector::sub(self.positions[k], self.positions[j]);
+			let edge_squared = vector::dot(edge, edge);
+			let left_normal = vector::cross(edge, vector::sub(self.positions[i], self.positions[j]));
+			let right_normal = vector::cross(vector::sub(self.positions[l], self.positions[j]), edge);
+			let left_squared = vector::dot(left_normal, left_normal);
+			let right_squared = vector::dot(right_normal, right_normal);
+			if edge_squared == 0.0 || left_squared == 0.0 || right_squared == 0.0 { continue; }
+
+			let edge_length = edge_squared.sqrt();
+			let left_gradient = vector::scale(left_normal, edge_length / left_squared);
+			let right_gradient = vector::scale(right_normal, edge_length / right_squared);
+			let left_ratio = vector::dot(vector::sub(self.positions[i], self.positions[j]), edge) / edge_squared;
+			let right_ratio = vector::dot(vector::sub(self.positions[l], self.positions[j]), edge) / edge_squared;
+
+			let gradient_j = vector::add(
+				vector::scale(left_gradient, left_ratio - 1.0),
+				vector::scale(right_gradient, right_ratio - 1.0),
+			);
+			let gradient_k = vector::add(
+				vector::scale(left_gradient, -left_ratio),
+				vector::scale(right_gradient, -right_ratio),
+			);
+
+			forces[i] = vector::add(forces[i], vector::scale(left_gradient, moment));
+			forces[l] = vector::add(forces[l], vector::scale(right_gradient, moment));
+			forces[j] = vector::add(forces[j], vector::scale(gradient_j, moment));
+			forces[k] = vector::add(forces[k], vector::scale(gradient_k, moment));
+		}
+
+		return forces;
+	}
+
+	pub fn step(&mut self) -> f64 {
+		let time_step = self.time_step();
+		self.update_angles();
+		let forces = self.forces();
+
+		let mut max_velocity: f64 = 0.0;
+		for (vertex_index, force) in forces.into_iter().enumerate() {
+			let velocity = vector::add(self.velocities[vertex_index], vector::scale(force, time_step));
+			self.velocities[vertex_index] = velocity;
+			self.positions[vertex_index] = vector::add(self.positions[vertex_index], vector::scale(velocity, time_step));
+			max_velocity = max_velocity.max(vector::length(velocity));
+		}
+		self.update_angles();
+		return max_velocity;
+	}
+
+	pub fn solve(&mut self) -> usize {
+		for step in 0..self.parameters.max_steps {
+			if self.step() < self.parameters.tolerance { return step + 1; }
+		}
+		return self.parameters.max_steps;
+	}
+
+	pub fn vertices_coordinates(&self) -> Vec<Vec<Real>> {
+		let mut vertices_coordinates: Vec<Vec<Real>> = Vec::new();
+		for position in self.positions.iter() {
+			vertices_coordinates.push(position.iter().map(|&value| Real::from_f64(value)).collect());
+		}
+		return vertices_coordinates;
+	}
+
+	pub fn make_frame(&self, frame_parent: usize) -> Frame {
+		let mut frame = Frame::new();
+		frame.classes.push(String::from("foldedForm"));
+		frame.attributes.push(String::from("3D"));
+		frame.graph.vertices_coordinates = self.vertices_coordinates();
+		frame.parent = Some(frame_parent);
+		frame.inherit = true;
+		return frame;
+	}
+}
\ No newline at end of file
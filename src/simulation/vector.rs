@@ -0,0 +1,27 @@
+pub fn add(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+	return [a[0] + b[0], a[1] + b[1], a[2] + b[2]];
+}
+
+pub fn sub(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+	return [a[0] - b[0], a[1] - b[1], a[2] - b[2]];
+}
+
+pub fn scale(a: [f64; 3], s: f64) -> [f64; 3] {
+	return [a[0] * s, a[1] * s, a[2] * s];
+}
+
+pub fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+	return a[0] * b[0] + a[1] * b[1] + a[2] * b[2];
+}
+
+pub fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+	return [
+		a[1] * b[2] - a[2] * b[1],
+		a[2] * b[0] - a[0] * b[2],
+		a[0] * b[1] - a[1] * b[0],
+	];
+}
+
+pub fn length(a: [f64; 3]) -> f64 {
+	return dot(a, a).sqrt();
+}
\ No newline at end of file